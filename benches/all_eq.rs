@@ -0,0 +1,18 @@
+
+extern crate rawslice;
+
+mod common;
+
+use std::hint::black_box;
+
+use common::bench;
+use rawslice::SliceIter;
+
+fn main() {
+    let zeros = vec![0u8; 1 << 20];
+
+    bench("all(|&x| x == 0)", || SliceIter::from(black_box(&zeros[..])).all(|&x| x == 0));
+    bench("all_eq(&0)", || SliceIter::from(black_box(&zeros[..])).all_eq(&0));
+    bench("any(|&x| x == 1)", || SliceIter::from(black_box(&zeros[..])).any(|&x| x == 1));
+    bench("any_eq(&1)", || SliceIter::from(black_box(&zeros[..])).any_eq(&1));
+}
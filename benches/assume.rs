@@ -0,0 +1,26 @@
+
+// Compare runs with and without `--features assume`:
+//
+//     cargo bench --bench assume
+//     cargo bench --bench assume --features assume
+
+extern crate rawslice;
+
+mod common;
+
+use common::bench;
+use rawslice::SliceIter;
+
+fn main() {
+    let data: Vec<u32> = (0..1 << 20).collect();
+
+    bench("fold", || {
+        SliceIter::from(&data[..]).fold(0u32, |acc, &x| acc.wrapping_add(x))
+    });
+    bench("position (no match)", || {
+        SliceIter::from(&data[..]).position(|&x| x == u32::MAX)
+    });
+    bench("rposition (no match)", || {
+        SliceIter::from(&data[..]).rposition(|&x| x == u32::MAX)
+    });
+}
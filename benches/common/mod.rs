@@ -0,0 +1,27 @@
+//! A minimal benchmark harness that runs on stable Rust.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const WARMUP: Duration = Duration::from_millis(100);
+const TARGET: Duration = Duration::from_millis(500);
+
+/// Run `f` repeatedly for about half a second and print the mean time per
+/// iteration.
+pub fn bench<F, R>(name: &str, mut f: F)
+    where F: FnMut() -> R
+{
+    let start = Instant::now();
+    while start.elapsed() < WARMUP {
+        black_box(f());
+    }
+
+    let mut iters = 0u32;
+    let start = Instant::now();
+    while start.elapsed() < TARGET {
+        black_box(f());
+        iters += 1;
+    }
+    let per_iter = start.elapsed() / iters;
+    println!("{:<40} {:>12?}/iter ({} iterations)", name, per_iter, iters);
+}
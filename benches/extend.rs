@@ -0,0 +1,22 @@
+
+extern crate rawslice;
+
+mod common;
+
+use common::bench;
+use rawslice::SliceIter;
+
+fn main() {
+    let data: Vec<u8> = (0..1 << 22).map(|x| x as u8).collect();
+
+    bench("Vec::extend(iter.cloned())", || {
+        let mut out = Vec::new();
+        out.extend(SliceIter::from(&data[..]).cloned());
+        out
+    });
+    bench("SliceIter::extend_into", || {
+        let mut out = Vec::new();
+        SliceIter::from(&data[..]).extend_into(&mut out);
+        out
+    });
+}
@@ -0,0 +1,39 @@
+
+extern crate rawslice;
+
+mod common;
+
+use common::bench;
+use rawslice::SliceIter;
+
+// Iterate the same elements, driven in blocks of `n`, so that `fold` runs
+// the unrolled loop per block. This was measured as a possible method, and
+// found to be slower than plain iteration, so it is kept only here.
+fn flatten_chunks(data: &[u32], n: usize) -> impl Iterator<Item = &u32> {
+    data.chunks(n).flat_map(SliceIter::from)
+}
+
+fn main() {
+    let data: Vec<u32> = (0..1 << 20).collect();
+
+    bench("next", || {
+        let mut acc = 0u32;
+        for &x in SliceIter::from(&data[..]) {
+            acc = acc.wrapping_add(x);
+        }
+        acc
+    });
+    for &n in &[16, 256, 4096] {
+        bench(&format!("flatten_chunks({}) next", n), || {
+            let mut acc = 0u32;
+            for &x in flatten_chunks(&data, n) {
+                acc = acc.wrapping_add(x);
+            }
+            acc
+        });
+        bench(&format!("flatten_chunks({}) fold", n), || {
+            flatten_chunks(&data, n)
+                .fold(0u32, |acc, &x| acc.wrapping_add(x))
+        });
+    }
+}
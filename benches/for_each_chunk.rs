@@ -0,0 +1,27 @@
+
+extern crate rawslice;
+
+mod common;
+
+use std::hint::black_box;
+
+use common::bench;
+use rawslice::SliceIter;
+
+fn main() {
+    let data: Vec<u32> = (0..1 << 20).collect();
+
+    for &n in &[3, 16, 256] {
+        bench(&format!("core chunks({}).for_each", n), || {
+            let mut acc = 0u32;
+            black_box(&data[..]).chunks(n).for_each(|c| acc = acc.wrapping_add(c[0]));
+            acc
+        });
+        bench(&format!("for_each_chunk({})", n), || {
+            let mut acc = 0u32;
+            SliceIter::from(black_box(&data[..]))
+                .for_each_chunk(n, |c| acc = acc.wrapping_add(c[0]));
+            acc
+        });
+    }
+}
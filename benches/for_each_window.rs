@@ -0,0 +1,28 @@
+extern crate rawslice;
+
+mod common;
+
+use std::hint::black_box;
+
+use common::bench;
+use rawslice::SliceIter;
+
+fn main() {
+    let data: Vec<u32> = (0..1 << 20).collect();
+
+    for &n in &[2, 8, 64] {
+        bench(&format!("windows({}).for_each", n), || {
+            let mut acc = 0u32;
+            SliceIter::from(black_box(&data[..]))
+                .windows(n)
+                .for_each(|w| acc = acc.wrapping_add(w[0] ^ w[n - 1]));
+            acc
+        });
+        bench(&format!("for_each_window({})", n), || {
+            let mut acc = 0u32;
+            SliceIter::from(black_box(&data[..]))
+                .for_each_window(n, |w| acc = acc.wrapping_add(w[0] ^ w[n - 1]));
+            acc
+        });
+    }
+}
@@ -0,0 +1,32 @@
+
+extern crate rawslice;
+
+mod common;
+
+use common::bench;
+use rawslice::SliceIter;
+
+const LEN: usize = 16 << 20;
+
+// Prefetch this many elements ahead (a few cache lines).
+const DISTANCE: usize = 64;
+
+fn main() {
+    let data: Vec<u32> = (0..LEN as u32).collect();
+
+    bench("fold 64 MiB", || {
+        SliceIter::from(&data[..]).fold(0u32, |acc, &x| acc.wrapping_add(x))
+    });
+
+    bench("fold 64 MiB with prefetch_ahead", || {
+        let mut iter = SliceIter::from(&data[..]);
+        let mut acc = 0u32;
+        while iter.len() >= 16 {
+            iter.prefetch_ahead(DISTANCE);
+            for _ in 0..16 {
+                acc = acc.wrapping_add(*iter.next().unwrap());
+            }
+        }
+        iter.fold(acc, |acc, &x| acc.wrapping_add(x))
+    });
+}
@@ -0,0 +1,21 @@
+
+extern crate rawslice;
+
+mod common;
+
+use common::bench;
+use rawslice::SliceIter;
+
+fn main() {
+    let data: Vec<u32> = (0..1 << 20).collect();
+
+    for &step in &[2, 3, 16] {
+        bench(&format!("core step_by({})", step), || {
+            data.iter().step_by(step).fold(0u32, |acc, &x| acc.wrapping_add(x))
+        });
+        bench(&format!("step_by_raw({})", step), || {
+            SliceIter::from(&data[..]).step_by_raw(step)
+                .fold(0u32, |acc, &x| acc.wrapping_add(x))
+        });
+    }
+}
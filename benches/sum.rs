@@ -0,0 +1,23 @@
+
+extern crate rawslice;
+
+mod common;
+
+use common::bench;
+use rawslice::SliceIter;
+
+fn main() {
+    let ints: Vec<i32> = (0..1 << 20).map(|x| x & 0xff).collect();
+    let floats: Vec<f32> = ints.iter().map(|&x| x as f32).collect();
+
+    bench("core sum i32", || ints.iter().sum::<i32>());
+    bench("SliceIter sum i32", || SliceIter::from(&ints[..]).sum::<i32>());
+    bench("core sum f32", || floats.iter().sum::<f32>());
+    bench("SliceIter sum f32", || SliceIter::from(&floats[..]).sum::<f32>());
+
+    #[cfg(feature = "multilane")]
+    {
+        bench("SliceIter multilane_sum i32", || SliceIter::from(&ints[..]).multilane_sum());
+        bench("SliceIter multilane_sum f32", || SliceIter::from(&floats[..]).multilane_sum());
+    }
+}
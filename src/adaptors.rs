@@ -0,0 +1,601 @@
+//! Iterator adaptors that keep raw pointer iteration
+
+use std::fmt;
+
+use rawpointer::ptrdistance;
+
+use iter::{SliceIter, FoldWhile};
+
+/// Iterator over every `step`th element, stepping the raw pointer.
+///
+/// Iterator element type is `&T`
+///
+/// Created with `SliceIter::step_by_raw`.
+#[derive(Debug)]
+pub struct StepByRaw<'a, T: 'a> {
+    iter: SliceIter<'a, T>,
+    step: usize,
+}
+
+impl<'a, T> Copy for StepByRaw<'a, T> { }
+impl<'a, T> Clone for StepByRaw<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> StepByRaw<'a, T> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, step: usize) -> Self {
+        assert!(step != 0, "step must be non-zero");
+        StepByRaw {
+            iter,
+            step,
+        }
+    }
+}
+
+impl<'a, T> Iterator for StepByRaw<'a, T> {
+    type Item = &'a T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.iter.len();
+        if len == 0 {
+            return None;
+        }
+        unsafe {
+            let ptr = self.iter.start();
+            let next = if self.step < len { ptr.add(self.step) } else { self.iter.end() };
+            self.iter.set_start(next);
+            Some(&*ptr)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for StepByRaw<'a, T> {
+    fn len(&self) -> usize {
+        match self.iter.len() {
+            0 => 0,
+            len => 1 + (len - 1) / self.step,
+        }
+    }
+}
+
+/// Iterator over elements and their indices, computing each index from the
+/// pointer distance to the start.
+///
+/// Iterator element type is `(usize, &T)`
+///
+/// Created with `SliceIter::enumerate_from_start`.
+#[derive(Debug)]
+pub struct EnumerateRaw<'a, T: 'a> {
+    base: *const T,
+    iter: SliceIter<'a, T>,
+}
+
+impl<'a, T> Copy for EnumerateRaw<'a, T> { }
+impl<'a, T> Clone for EnumerateRaw<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> EnumerateRaw<'a, T> {
+    pub(crate) fn new(iter: SliceIter<'a, T>) -> Self {
+        EnumerateRaw {
+            base: iter.start(),
+            iter,
+        }
+    }
+}
+
+impl<'a, T> Iterator for EnumerateRaw<'a, T> {
+    type Item = (usize, &'a T);
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = ptrdistance(self.base, self.iter.start());
+        self.iter.next().map(|elt| (index, elt))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for EnumerateRaw<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let elt = self.iter.next_back()?;
+        Some((ptrdistance(self.base, self.iter.end()), elt))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for EnumerateRaw<'a, T> { }
+
+/// Iterator over pointers to the elements.
+///
+/// Iterator element type is `*const T`
+///
+/// Created with `SliceIter::ptrs`.
+#[derive(Debug)]
+pub struct Ptrs<'a, T: 'a> {
+    iter: SliceIter<'a, T>,
+}
+
+impl<'a, T> Copy for Ptrs<'a, T> { }
+impl<'a, T> Clone for Ptrs<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> Ptrs<'a, T> {
+    pub(crate) fn new(iter: SliceIter<'a, T>) -> Self {
+        Ptrs { iter }
+    }
+}
+
+impl<'a, T> Iterator for Ptrs<'a, T> {
+    type Item = *const T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|elt| elt as *const T)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Ptrs<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|elt| elt as *const T)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Ptrs<'a, T> { }
+
+/// Iterator that repeats the elements a fixed number of times, resetting
+/// the raw pointer.
+///
+/// Iterator element type is `&T`
+///
+/// Created with `SliceIter::cycle_n`.
+#[derive(Debug)]
+pub struct CycleN<'a, T: 'a> {
+    orig: SliceIter<'a, T>,
+    iter: SliceIter<'a, T>,
+    // number of passes left after the current one
+    times: usize,
+}
+
+impl<'a, T> Copy for CycleN<'a, T> { }
+impl<'a, T> Clone for CycleN<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> CycleN<'a, T> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, times: usize) -> Self {
+        if times == 0 {
+            CycleN {
+                orig: iter,
+                iter: SliceIter::default(),
+                times: 0,
+            }
+        } else {
+            CycleN {
+                orig: iter,
+                iter,
+                times: times - 1,
+            }
+        }
+    }
+}
+
+impl<'a, T> Iterator for CycleN<'a, T> {
+    type Item = &'a T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() == 0 {
+            if self.times == 0 || self.orig.len() == 0 {
+                return None;
+            }
+            self.times -= 1;
+            self.iter = self.orig;
+        }
+        unsafe {
+            Some(self.iter.next_unchecked())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.orig.len().checked_mul(self.times)
+            .and_then(|n| n.checked_add(self.iter.len()));
+        (len.unwrap_or(usize::MAX), len)
+    }
+}
+
+/// Iterator adaptor that threads a state through its elements, stepping the
+/// raw pointer.
+///
+/// Iterator element type is `B`, the value returned by the closure. The
+/// iterator stops when the closure returns `None`.
+///
+/// Created with `SliceIter::scan_raw`.
+pub struct ScanRaw<'a, T: 'a, St, F> {
+    iter: SliceIter<'a, T>,
+    state: St,
+    f: F,
+}
+
+impl<'a, T, St: Clone, F: Clone> Clone for ScanRaw<'a, T, St, F> {
+    fn clone(&self) -> Self {
+        ScanRaw {
+            iter: self.iter,
+            state: self.state.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<'a, T, St, F> ScanRaw<'a, T, St, F> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, state: St, f: F) -> Self {
+        ScanRaw {
+            iter,
+            state,
+            f,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, St: fmt::Debug, F> fmt::Debug for ScanRaw<'a, T, St, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ScanRaw")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<'a, T, St, B, F> Iterator for ScanRaw<'a, T, St, F>
+    where F: FnMut(&mut St, &'a T) -> Option<B>,
+{
+    type Item = B;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let elt = self.iter.next()?;
+        let result = (self.f)(&mut self.state, elt);
+        if result.is_none() {
+            unsafe {
+                let end = self.iter.end();
+                self.iter.set_start(end);
+            }
+        }
+        result
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.iter.len()))
+    }
+}
+
+/// Iterator adaptor that calls a closure on each element before yielding
+/// it, keeping the unrolled searching methods.
+///
+/// Iterator element type is `&T`
+///
+/// Created with `SliceIter::inspect_raw`.
+pub struct InspectRaw<'a, T: 'a, F> {
+    iter: SliceIter<'a, T>,
+    f: F,
+}
+
+impl<'a, T, F: Clone> Clone for InspectRaw<'a, T, F> {
+    fn clone(&self) -> Self {
+        InspectRaw {
+            iter: self.iter,
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<'a, T, F> InspectRaw<'a, T, F> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, f: F) -> Self {
+        InspectRaw {
+            iter,
+            f,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, F> fmt::Debug for InspectRaw<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InspectRaw")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<'a, T, F> Iterator for InspectRaw<'a, T, F>
+    where F: FnMut(&T),
+{
+    type Item = &'a T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let elt = self.iter.next()?;
+        (self.f)(elt);
+        Some(elt)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+        where G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, elt| {
+            f(elt);
+            g(acc, elt)
+        })
+    }
+
+    fn all<P>(&mut self, mut predicate: P) -> bool
+        where P: FnMut(Self::Item) -> bool,
+    {
+        let f = &mut self.f;
+        self.iter.all(move |elt| {
+            f(elt);
+            predicate(elt)
+        })
+    }
+
+    fn any<P>(&mut self, mut predicate: P) -> bool
+        where P: FnMut(Self::Item) -> bool,
+    {
+        !self.all(move |elt| !predicate(elt))
+    }
+
+    fn find<P>(&mut self, mut predicate: P) -> Option<Self::Item>
+        where P: FnMut(&Self::Item) -> bool,
+    {
+        let f = &mut self.f;
+        self.iter.find(move |elt| {
+            f(elt);
+            predicate(elt)
+        })
+    }
+
+    fn position<P>(&mut self, mut predicate: P) -> Option<usize>
+        where P: FnMut(Self::Item) -> bool,
+    {
+        let f = &mut self.f;
+        self.iter.position(move |elt| {
+            f(elt);
+            predicate(elt)
+        })
+    }
+}
+
+impl<'a, T, F> DoubleEndedIterator for InspectRaw<'a, T, F>
+    where F: FnMut(&T),
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let elt = self.iter.next_back()?;
+        (self.f)(elt);
+        Some(elt)
+    }
+}
+
+impl<'a, T, F> ExactSizeIterator for InspectRaw<'a, T, F>
+    where F: FnMut(&T),
+{ }
+
+/// Iterator adaptor that maps each element with a closure, keeping the
+/// unrolled searching methods.
+///
+/// Iterator element type is `B`, the value returned by the closure.
+///
+/// Created with `SliceIter::map_raw`.
+pub struct MapRaw<'a, T: 'a, F> {
+    iter: SliceIter<'a, T>,
+    f: F,
+}
+
+impl<'a, T, F: Clone> Clone for MapRaw<'a, T, F> {
+    fn clone(&self) -> Self {
+        MapRaw {
+            iter: self.iter,
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<'a, T, F> MapRaw<'a, T, F> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, f: F) -> Self {
+        MapRaw {
+            iter,
+            f,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, F> fmt::Debug for MapRaw<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapRaw")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<'a, T, B, F> Iterator for MapRaw<'a, T, F>
+    where F: FnMut(&'a T) -> B,
+{
+    type Item = B;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(&mut self.f)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+        where G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, elt| g(acc, f(elt)))
+    }
+
+    fn all<P>(&mut self, mut predicate: P) -> bool
+        where P: FnMut(Self::Item) -> bool,
+    {
+        let f = &mut self.f;
+        self.iter.all(move |elt| predicate(f(elt)))
+    }
+
+    fn any<P>(&mut self, mut predicate: P) -> bool
+        where P: FnMut(Self::Item) -> bool,
+    {
+        let f = &mut self.f;
+        self.iter.any(move |elt| predicate(f(elt)))
+    }
+
+    fn find<P>(&mut self, mut predicate: P) -> Option<Self::Item>
+        where P: FnMut(&Self::Item) -> bool,
+    {
+        let f = &mut self.f;
+        self.iter.fold_while(None, move |_, elt| {
+            let x = f(elt);
+            if predicate(&x) {
+                FoldWhile::Done(Some(x))
+            } else {
+                FoldWhile::Continue(None)
+            }
+        })
+    }
+
+    fn position<P>(&mut self, mut predicate: P) -> Option<usize>
+        where P: FnMut(Self::Item) -> bool,
+    {
+        let f = &mut self.f;
+        self.iter.position(move |elt| predicate(f(elt)))
+    }
+}
+
+impl<'a, T, B, F> DoubleEndedIterator for MapRaw<'a, T, F>
+    where F: FnMut(&'a T) -> B,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(&mut self.f)
+    }
+}
+
+impl<'a, T, B, F> ExactSizeIterator for MapRaw<'a, T, F>
+    where F: FnMut(&'a T) -> B,
+{ }
+
+/// Iterator over the indices of the elements that satisfy a predicate.
+///
+/// Iterator element type is `usize`
+///
+/// Created with `SliceIter::positions`.
+pub struct Positions<'a, T: 'a, F> {
+    base: *const T,
+    iter: SliceIter<'a, T>,
+    pred: F,
+}
+
+impl<'a, T, F: Clone> Clone for Positions<'a, T, F> {
+    fn clone(&self) -> Self {
+        Positions {
+            base: self.base,
+            iter: self.iter,
+            pred: self.pred.clone(),
+        }
+    }
+}
+
+impl<'a, T, F> Positions<'a, T, F> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, pred: F) -> Self {
+        Positions {
+            base: iter.start(),
+            iter,
+            pred,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, F> fmt::Debug for Positions<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Positions")
+            .field("base", &self.base)
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<'a, T, F> Iterator for Positions<'a, T, F>
+    where F: FnMut(&T) -> bool,
+{
+    type Item = usize;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let base = self.base;
+        self.iter.find_ptr(&mut self.pred).map(|p| ptrdistance(base, p))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.iter.len()))
+    }
+}
+
+/// Iterator that alternates between the elements of two `SliceIter`s.
+///
+/// Iterator element type is `&T`
+///
+/// Created with `SliceIter::interleave`.
+#[derive(Debug)]
+pub struct Interleave<'a, T: 'a> {
+    a: SliceIter<'a, T>,
+    b: SliceIter<'a, T>,
+    flag: bool,
+}
+
+impl<'a, T> Copy for Interleave<'a, T> { }
+impl<'a, T> Clone for Interleave<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> Interleave<'a, T> {
+    pub(crate) fn new(a: SliceIter<'a, T>, b: SliceIter<'a, T>) -> Self {
+        Interleave {
+            a,
+            b,
+            flag: false,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Interleave<'a, T> {
+    type Item = &'a T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.flag = !self.flag;
+        if self.flag {
+            self.a.next().or_else(|| self.b.next())
+        } else {
+            self.b.next().or_else(|| self.a.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.a.len() + self.b.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Interleave<'a, T> { }
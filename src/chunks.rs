@@ -0,0 +1,302 @@
+//! Non-overlapping chunk iterators
+
+use std::cmp::min;
+use std::slice;
+
+use iter::SliceIter;
+use iter_mut::SliceIterMut;
+
+/// Iterator over non-overlapping mutable chunks of a `SliceIterMut`.
+///
+/// Iterator element type is `&mut [T]`
+///
+/// Created with `SliceIterMut::chunks_mut`.
+#[derive(Debug)]
+pub struct ChunksMut<'a, T: 'a> {
+    iter: SliceIterMut<'a, T>,
+    size: usize,
+}
+
+impl<'a, T> ChunksMut<'a, T> {
+    pub(crate) fn new(iter: SliceIterMut<'a, T>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        ChunksMut {
+            iter,
+            size,
+        }
+    }
+}
+
+impl<'a, T> Iterator for ChunksMut<'a, T> {
+    type Item = &'a mut [T];
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.iter.len();
+        if len == 0 {
+            return None;
+        }
+        let n = min(self.size, len);
+        unsafe {
+            let start = self.iter.start();
+            self.iter.set_start(start.add(n));
+            Some(slice::from_raw_parts_mut(start, n))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len().div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ChunksMut<'a, T> { }
+
+/// Iterator over non-overlapping chunks of a `SliceIter`, starting from
+/// the back.
+///
+/// Iterator element type is `&[T]`
+///
+/// Created with `SliceIter::rchunks`.
+#[derive(Debug)]
+pub struct RChunks<'a, T: 'a> {
+    iter: SliceIter<'a, T>,
+    size: usize,
+}
+
+impl<'a, T> Copy for RChunks<'a, T> { }
+impl<'a, T> Clone for RChunks<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> RChunks<'a, T> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        RChunks {
+            iter,
+            size,
+        }
+    }
+}
+
+impl<'a, T> Iterator for RChunks<'a, T> {
+    type Item = &'a [T];
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.iter.len();
+        if len == 0 {
+            return None;
+        }
+        let n = min(self.size, len);
+        unsafe {
+            let start = self.iter.end().sub(n);
+            self.iter.set_end(start);
+            Some(slice::from_raw_parts(start, n))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len().div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RChunks<'a, T> { }
+
+/// Iterator over non-overlapping chunks of exactly `n` elements of a
+/// `SliceIter`, starting from the back.
+///
+/// Iterator element type is `&[T]`
+///
+/// Created with `SliceIter::rchunks_exact`.
+#[derive(Debug)]
+pub struct RChunksExact<'a, T: 'a> {
+    iter: SliceIter<'a, T>,
+    rem: &'a [T],
+    size: usize,
+}
+
+impl<'a, T> Copy for RChunksExact<'a, T> { }
+impl<'a, T> Clone for RChunksExact<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> RChunksExact<'a, T> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        let (rem, iter) = iter.split_at(iter.len() % size);
+        RChunksExact {
+            iter,
+            rem: rem.into_slice(),
+            size,
+        }
+    }
+
+    /// Return the elements at the front that do not fill a chunk
+    pub fn remainder(&self) -> &'a [T] {
+        self.rem
+    }
+}
+
+impl<'a, T> Iterator for RChunksExact<'a, T> {
+    type Item = &'a [T];
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() == 0 {
+            return None;
+        }
+        unsafe {
+            let start = self.iter.end().sub(self.size);
+            self.iter.set_end(start);
+            Some(slice::from_raw_parts(start, self.size))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len() / self.size;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RChunksExact<'a, T> { }
+
+/// Iterator over non-overlapping chunks of a `SliceIter`, as `SliceIter`s.
+///
+/// Iterator element type is `SliceIter<'a, T>`
+///
+/// Created with `SliceIter::chunk_iters`.
+#[derive(Debug)]
+pub struct ChunkIters<'a, T: 'a> {
+    iter: SliceIter<'a, T>,
+    size: usize,
+}
+
+impl<'a, T> Copy for ChunkIters<'a, T> { }
+impl<'a, T> Clone for ChunkIters<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> ChunkIters<'a, T> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        ChunkIters {
+            iter,
+            size,
+        }
+    }
+}
+
+impl<'a, T> Iterator for ChunkIters<'a, T> {
+    type Item = SliceIter<'a, T>;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.iter.len();
+        if len == 0 {
+            return None;
+        }
+        let n = min(self.size, len);
+        unsafe {
+            let start = self.iter.start();
+            let end = start.add(n);
+            self.iter.set_start(end);
+            Some(SliceIter::new(start, end))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len().div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ChunkIters<'a, T> { }
+
+/// Iterator over non-overlapping mutable chunks of exactly `N` elements,
+/// as array references.
+///
+/// Iterator element type is `&mut [T; N]`
+///
+/// Created with `SliceIterMut::chunks_exact_mut`.
+#[derive(Debug)]
+pub struct ChunksExactMut<'a, T: 'a, const N: usize> {
+    iter: SliceIterMut<'a, T>,
+    rem: SliceIterMut<'a, T>,
+}
+
+impl<'a, T, const N: usize> ChunksExactMut<'a, T, N> {
+    pub(crate) fn new(iter: SliceIterMut<'a, T>) -> Self {
+        const { assert!(N != 0, "chunk size must be non-zero") };
+        let len = iter.len();
+        unsafe {
+            let mid = iter.start().add(len - len % N);
+            ChunksExactMut {
+                iter: SliceIterMut::new(iter.start(), mid),
+                rem: SliceIterMut::new(mid, iter.end()),
+            }
+        }
+    }
+
+    /// Return the remainder: the last elements that don't fill a whole
+    /// chunk.
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.rem.into_slice()
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ChunksExactMut<'a, T, N> {
+    type Item = &'a mut [T; N];
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() == 0 {
+            return None;
+        }
+        unsafe {
+            let start = self.iter.start();
+            self.iter.set_start(start.add(N));
+            Some(&mut *(start as *mut [T; N]))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len() / N;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for ChunksExactMut<'a, T, N> { }
+
+/// Iterator over non-overlapping pairs of adjacent mutable elements.
+///
+/// Iterator element type is `(&mut T, &mut T)`
+///
+/// Created with `SliceIterMut::pairs_mut`.
+#[derive(Debug)]
+pub struct PairsMut<'a, T: 'a> {
+    chunks: ChunksExactMut<'a, T, 2>,
+}
+
+impl<'a, T> PairsMut<'a, T> {
+    pub(crate) fn new(iter: SliceIterMut<'a, T>) -> Self {
+        PairsMut {
+            chunks: ChunksExactMut::new(iter),
+        }
+    }
+
+    /// Return the last element if it is left over without a pair.
+    pub fn into_remainder(self) -> Option<&'a mut T> {
+        self.chunks.into_remainder().first_mut()
+    }
+}
+
+impl<'a, T> Iterator for PairsMut<'a, T> {
+    type Item = (&'a mut T, &'a mut T);
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(|[a, b]| (a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for PairsMut<'a, T> { }
@@ -0,0 +1,54 @@
+//! Error types
+
+use std::error::Error;
+use std::fmt;
+
+/// An error from a checked `SliceIter` constructor or conversion.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SliceIterError {
+    /// The start or end pointer is null
+    Null,
+    /// The start pointer is after the end pointer
+    Unordered,
+    /// A size in bytes is not a multiple of the element size
+    SizeNotMultiple,
+    /// A pointer is not, or can not be made, aligned for the element type
+    Misaligned,
+    /// The element type is zero-sized, which is not supported
+    ZeroSized,
+    /// An offset is past the end of the range
+    OutOfBounds,
+}
+
+impl fmt::Display for SliceIterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match *self {
+            SliceIterError::Null => "null pointer",
+            SliceIterError::Unordered => "start pointer is after end pointer",
+            SliceIterError::SizeNotMultiple => "size is not a multiple of the element size",
+            SliceIterError::Misaligned => "pointer is misaligned for the element type",
+            SliceIterError::ZeroSized => "element type is zero-sized",
+            SliceIterError::OutOfBounds => "offset is past the end of the range",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Error for SliceIterError { }
+
+/// An index out of bounds error from `SliceIter::try_index`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct IndexError {
+    /// The requested index
+    pub index: usize,
+    /// The length of the iterator at the time of the lookup
+    pub len: usize,
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "index {} is out of bounds for length {}", self.index, self.len)
+    }
+}
+
+impl Error for IndexError { }
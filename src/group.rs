@@ -0,0 +1,233 @@
+//! Iterators over runs of adjacent elements
+
+use std::fmt;
+
+use iter::SliceIter;
+
+/// Iterator over maximal runs of adjacent elements where each consecutive
+/// pair satisfies a predicate.
+///
+/// Iterator element type is `&[T]`
+///
+/// Created with `SliceIter::group_by`.
+pub struct GroupBy<'a, T: 'a, F> {
+    iter: SliceIter<'a, T>,
+    same: F,
+}
+
+impl<'a, T, F: Clone> Clone for GroupBy<'a, T, F> {
+    fn clone(&self) -> Self {
+        GroupBy {
+            iter: self.iter,
+            same: self.same.clone(),
+        }
+    }
+}
+
+impl<'a, T, F> GroupBy<'a, T, F> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, same: F) -> Self {
+        GroupBy {
+            iter,
+            same,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, F> fmt::Debug for GroupBy<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupBy")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<'a, T, F> Iterator for GroupBy<'a, T, F>
+    where F: FnMut(&T, &T) -> bool,
+{
+    type Item = &'a [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.iter.as_slice();
+        let mut prev = self.iter.next()?;
+        let mut len = 1;
+        while let Some(elt) = self.iter.peek_next() {
+            if !(self.same)(prev, elt) {
+                break;
+            }
+            prev = elt;
+            len += 1;
+            unsafe {
+                self.iter.next_unchecked();
+            }
+        }
+        unsafe {
+            Some(rest.get_unchecked(..len))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len();
+        (if len == 0 { 0 } else { 1 }, Some(len))
+    }
+}
+
+/// Iterator over the first element of each run of equal adjacent elements.
+///
+/// Iterator element type is `&T`
+///
+/// Created with `SliceIter::dedup`.
+#[derive(Debug)]
+pub struct Dedup<'a, T: 'a> {
+    iter: SliceIter<'a, T>,
+}
+
+impl<'a, T> Copy for Dedup<'a, T> { }
+impl<'a, T> Clone for Dedup<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> Dedup<'a, T> {
+    pub(crate) fn new(iter: SliceIter<'a, T>) -> Self {
+        Dedup { iter }
+    }
+}
+
+impl<'a, T: PartialEq> Iterator for Dedup<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        while let Some(elt) = self.iter.peek_next() {
+            if elt != first {
+                break;
+            }
+            unsafe {
+                self.iter.next_unchecked();
+            }
+        }
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len();
+        (if len == 0 { 0 } else { 1 }, Some(len))
+    }
+}
+
+/// Iterator over the first element of each run of adjacent elements with
+/// equal keys.
+///
+/// Iterator element type is `&T`
+///
+/// Created with `SliceIter::dedup_by_key`.
+pub struct DedupByKey<'a, T: 'a, F, K> {
+    iter: SliceIter<'a, T>,
+    key: F,
+    // the key of the next element, if it is already computed
+    next_key: Option<K>,
+}
+
+impl<'a, T, F: Clone, K: Clone> Clone for DedupByKey<'a, T, F, K> {
+    fn clone(&self) -> Self {
+        DedupByKey {
+            iter: self.iter,
+            key: self.key.clone(),
+            next_key: self.next_key.clone(),
+        }
+    }
+}
+
+impl<'a, T, F, K> DedupByKey<'a, T, F, K> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, key: F) -> Self {
+        DedupByKey {
+            iter,
+            key,
+            next_key: None,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, F, K> fmt::Debug for DedupByKey<'a, T, F, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DedupByKey")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<'a, T, F, K> Iterator for DedupByKey<'a, T, F, K>
+    where F: FnMut(&T) -> K,
+          K: PartialEq,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let first_key = match self.next_key.take() {
+            Some(key) => key,
+            None => (self.key)(first),
+        };
+        while let Some(elt) = self.iter.peek_next() {
+            let key = (self.key)(elt);
+            if key != first_key {
+                self.next_key = Some(key);
+                break;
+            }
+            unsafe {
+                self.iter.next_unchecked();
+            }
+        }
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len();
+        (if len == 0 { 0 } else { 1 }, Some(len))
+    }
+}
+
+/// Iterator over the runs of adjacent elements where each consecutive pair
+/// satisfies a predicate, as the first element and the length of each run.
+///
+/// Iterator element type is `(&T, usize)`
+///
+/// Created with `SliceIter::run_length`.
+pub struct RunLength<'a, T: 'a, F> {
+    groups: GroupBy<'a, T, F>,
+}
+
+impl<'a, T, F: Clone> Clone for RunLength<'a, T, F> {
+    fn clone(&self) -> Self {
+        RunLength {
+            groups: self.groups.clone(),
+        }
+    }
+}
+
+impl<'a, T, F> RunLength<'a, T, F> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, same: F) -> Self {
+        RunLength {
+            groups: GroupBy::new(iter, same),
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, F> fmt::Debug for RunLength<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RunLength")
+            .field("iter", &self.groups.iter)
+            .finish()
+    }
+}
+
+impl<'a, T, F> Iterator for RunLength<'a, T, F>
+    where F: FnMut(&T, &T) -> bool,
+{
+    type Item = (&'a T, usize);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.groups.next().map(|run| unsafe {
+            (run.get_unchecked(0), run.len())
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.groups.size_hint()
+    }
+}
@@ -0,0 +1,14 @@
+//! Serde serialization support
+
+use serde::{Serialize, Serializer};
+
+use iter::SliceIter;
+
+/// Serialize the remaining elements as a sequence
+impl<'a, T: Serialize> Serialize for SliceIter<'a, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.collect_seq(self.as_slice())
+    }
+}
@@ -1,14 +1,40 @@
 //! Slice iterators
 
-use std::mem::size_of;
+use std::mem::{align_of, size_of, MaybeUninit};
+use std::ptr;
+use std::ptr::NonNull;
+use std::array;
+use std::borrow::Borrow;
+use std::cmp::{min, Ordering};
 use std::marker::PhantomData;
-use std::ops::Index;
+use std::ops::ControlFlow;
+use std::ops::{Index, Range, RangeFrom, RangeFull, RangeTo};
+use std::ops::{RangeInclusive, RangeToInclusive};
+use std::ops::{Bound, RangeBounds};
 use std::slice;
 use std::slice::{Iter as CoreSliceIter};
 
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use rawpointer::PointerExt;
 use rawpointer::ptrdistance;
 
+use adaptors::{StepByRaw, EnumerateRaw, Ptrs, ScanRaw, CycleN, InspectRaw, MapRaw,
+               Positions, Interleave};
+use chunks::{RChunks, RChunksExact, ChunkIters};
+use error::{SliceIterError, IndexError};
+use group::{GroupBy, Dedup, DedupByKey, RunLength};
+use offset_cursor::OffsetCursor;
+#[cfg(feature = "multilane")]
+use multilane::MultiLaneSum;
+use rev::RevSliceIter;
+use split::{Split, SplitInclusive, SplitTerminator, SplitSkipEmpty, RSplit, RSplitN};
+use tracked::TrackedSliceIter;
+use windows::{Windows, ArrayWindows, WindowsN};
+
 
 /// Slice (contiguous data) iterator.
 ///
@@ -24,12 +50,21 @@ use rawpointer::ptrdistance;
 /// **Extra Features:**
 ///
 /// + unrolled `all, find, position, rposition`,
+/// + unrolled `fold`, which is also used by `sum` and `product`
+/// + `min_by`, `max_by` using the unrolled `fold`
+/// + public unrolled `fold_while`, `rfold_while` for custom early exits
 /// + accessors (incl. mutable) of start, end pointers
 /// + construct from raw pointers
 /// + native `peek_next`
 /// + native `next_unchecked`.
 /// + implement `Copy`, `Index`, `Default`
 ///
+/// Because `SliceIter` is `Copy`, iterating a `SliceIter` by value silently
+/// iterates a copy, and the original is not advanced. Use `.cursor()` (or
+/// `.by_ref()`) when partially consuming it. `Copy` is kept since it makes
+/// multi-pass scans and passing iterators around cheap and convenient; the
+/// cost is this pitfall.
+///
 /// Notice that we don't have access to or use all the unstable features
 /// libcore can use, so some of the perks of the libcore slice iterator
 /// are missing.
@@ -37,7 +72,8 @@ use rawpointer::ptrdistance;
 /// **Missing Features:**
 ///
 /// + No `TrustedRandomAccess` or `TrustedLen` (unstable features)
-/// + No `std::intrinsics::assume`.
+/// + No `std::intrinsics::assume`; the `assume` feature enables a best
+///   effort replacement, see `hint_nonempty`.
 /// + No support for zero-sized iterator element type
 #[derive(Debug)]
 pub struct SliceIter<'a, T: 'a> {
@@ -46,83 +82,1853 @@ pub struct SliceIter<'a, T: 'a> {
     ty: PhantomData<&'a T>,
 }
 
-impl<'a, T> Copy for SliceIter<'a, T> { }
-impl<'a, T> Clone for SliceIter<'a, T> {
-    fn clone(&self) -> Self { *self }
-}
+unsafe impl<'a, T: Sync> Send for SliceIter<'a, T> { }
+unsafe impl<'a, T: Sync> Sync for SliceIter<'a, T> { }
+
+impl<'a, T> Copy for SliceIter<'a, T> { }
+impl<'a, T> Clone for SliceIter<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> SliceIter<'a, T> {
+    /// Create a new slice iterator
+    ///
+    /// See also ``SliceIter::from, SliceIter::default``.
+    ///
+    /// Panics if `T` is a zero-sized type. That case is not supported.
+    ///
+    /// With the `paranoid` feature, also panics if either pointer is null
+    /// or if `start` is after `end`. In debug builds, also panics if either
+    /// pointer is misaligned for `T`.
+    #[inline]
+    pub unsafe fn new(start: *const T, end: *const T) -> Self {
+        assert!(size_of::<T>() != 0);
+        check_pointers(start, end);
+        check_aligned(start);
+        check_aligned(end);
+        SliceIter {
+            ptr: start,
+            end,
+            ty: PhantomData,
+        }
+    }
+
+    /// Create a new slice iterator, checking the pointers first
+    ///
+    /// Return an error if either pointer is null, if either pointer is
+    /// misaligned for `T`, if `start` is after `end`, or if the distance
+    /// between them is not a multiple of the element size.
+    ///
+    /// Unsafe because the pointers must still be valid for reading the
+    /// range between them, for the lifetime `'a`.
+    ///
+    /// Return an error if `T` is a zero-sized type. That case is not
+    /// supported.
+    pub unsafe fn new_checked(start: *const T, end: *const T) -> Result<Self, SliceIterError> {
+        if size_of::<T>() == 0 {
+            return Err(SliceIterError::ZeroSized);
+        }
+        if start.is_null() || end.is_null() {
+            return Err(SliceIterError::Null);
+        }
+        // `end` is then aligned too, once the distance is checked below
+        if (start as usize) % align_of::<T>() != 0 {
+            return Err(SliceIterError::Misaligned);
+        }
+        if start > end {
+            return Err(SliceIterError::Unordered);
+        }
+        let bytes = end as usize - start as usize;
+        if bytes % size_of::<T>() != 0 {
+            return Err(SliceIterError::SizeNotMultiple);
+        }
+        Ok(SliceIter::new(start, end))
+    }
+
+    /// Create a new slice iterator from a slice, or return an error if `T`
+    /// is a zero-sized type.
+    ///
+    /// This is `SliceIter::from` for generic code that must handle every
+    /// `T` without panicking. It is not a `TryFrom` impl, since that would
+    /// conflict with the `From` impl.
+    pub fn try_from_slice(slice: &'a [T]) -> Result<Self, SliceIterError> {
+        if size_of::<T>() == 0 {
+            Err(SliceIterError::ZeroSized)
+        } else {
+            Ok(SliceIter::from(slice))
+        }
+    }
+
+    /// Create a new slice iterator from a start pointer and a length
+    ///
+    /// Unsafe because the pointer must be valid for reading `len` elements,
+    /// for the lifetime `'a`.
+    ///
+    /// Panics if `T` is a zero-sized type. That case is not supported.
+    pub unsafe fn from_raw_parts(ptr: *const T, len: usize) -> Self {
+        SliceIter::new(ptr, ptr.add(len))
+    }
+
+    /// Create a new slice iterator over a slice of possibly uninitialized
+    /// elements
+    ///
+    /// This reads a freshly filled buffer without a separate `assume_init`
+    /// pass; `MaybeUninit<T>` has the same layout as `T`.
+    ///
+    /// Unsafe because all the elements of `slice` must be initialized.
+    ///
+    /// Panics if `T` is a zero-sized type. That case is not supported.
+    pub unsafe fn from_maybe_uninit(slice: &'a [MaybeUninit<T>]) -> Self {
+        let ptr = slice.as_ptr() as *const T;
+        SliceIter::new(ptr, ptr.add(slice.len()))
+    }
+
+    /// Return the start pointer and the number of remaining elements
+    ///
+    /// This is the inverse of `from_raw_parts`.
+    pub fn into_raw_parts(self) -> (*const T, usize) {
+        (self.ptr, self.len())
+    }
+
+    /// Create a new slice iterator from an array reference
+    ///
+    /// ```
+    /// use rawslice::SliceIter;
+    ///
+    /// let iter = SliceIter::from_array(&[1, 2, 3]);
+    /// assert_eq!(iter.as_slice(), &[1, 2, 3]);
+    /// ```
+    ///
+    /// Panics if `T` is a zero-sized type. That case is not supported.
+    pub fn from_array<const N: usize>(arr: &'a [T; N]) -> Self {
+        SliceIter::from(&arr[..])
+    }
+
+    /// Return a mutable borrow of the iterator, for partially consuming it.
+    ///
+    /// Since `SliceIter` is `Copy`, a loop over the iterator by value
+    /// consumes a copy of it; loop over the cursor instead to advance the
+    /// original.
+    ///
+    /// ```
+    /// use rawslice::SliceIter;
+    ///
+    /// let data = [1, 2, 3, 4];
+    /// let mut iter = SliceIter::from(&data[..]);
+    ///
+    /// // Pitfall: this iterates a copy of `iter`
+    /// for _ in iter {
+    ///     break;
+    /// }
+    /// assert_eq!(iter.len(), 4);
+    ///
+    /// for &x in iter.cursor() {
+    ///     if x == 2 {
+    ///         break;
+    ///     }
+    /// }
+    /// assert_eq!(iter.as_slice(), &[3, 4]);
+    /// ```
+    pub fn cursor(&mut self) -> &mut Self {
+        self
+    }
+
+    /// Return the start pointer
+    pub fn start(&self) -> *const T {
+        self.ptr
+    }
+
+    /// Return the end pointer
+    pub fn end(&self) -> *const T {
+        self.end
+    }
+
+    /// Return mutable reference to the start pointer
+    ///
+    /// Unsafe because it is easy to violate memory safety by setting
+    /// the pointer outside the data's valid range.
+    pub unsafe fn start_mut(&mut self) -> &mut *const T {
+        &mut self.ptr
+    }
+
+    /// Return mutable reference to the end pointer
+    ///
+    /// Unsafe because it is easy to violate memory safety by setting
+    /// the pointer outside the data's valid range.
+    pub unsafe fn end_mut(&mut self) -> &mut *const T {
+        &mut self.end
+    }
+
+    /// Set the start pointer
+    ///
+    /// Unsafe because the caller must keep the start pointer within the
+    /// data's valid range, in the same allocation as the end pointer, and
+    /// not past the end pointer.
+    pub unsafe fn set_start(&mut self, p: *const T) {
+        self.ptr = p;
+    }
+
+    /// Advance the start pointer to `p`, skipping the elements before it.
+    ///
+    /// This resumes iteration at a position found by other code, for
+    /// example a pointer returned by a C routine scanning the same buffer.
+    ///
+    /// Unsafe because `p` must point into the same allocation as the
+    /// iterator's range, at an element boundary, and lie between the current
+    /// start and end pointers (inclusive). The bounds are checked with
+    /// a debug assertion only.
+    pub unsafe fn advance_to(&mut self, p: *const T) {
+        debug_assert!(self.ptr <= p && p <= self.end);
+        self.ptr = p;
+    }
+
+    /// Set the end pointer
+    ///
+    /// Unsafe because the caller must keep the end pointer within the
+    /// data's valid range, in the same allocation as the start pointer, and
+    /// not before the start pointer.
+    pub unsafe fn set_end(&mut self, p: *const T) {
+        self.end = p;
+    }
+
+    /// Return the next iterator element, without stepping the iterator.
+    pub fn peek_next(&self) -> Option<<Self as Iterator>::Item> {
+        if self.ptr != self.end {
+            unsafe {
+                Some(&*self.ptr)
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Return the number of remaining elements
+    ///
+    /// This is the same as `len`, without needing `ExactSizeIterator`
+    /// in scope.
+    ///
+    /// Adaptors that wrap a `SliceIter` can use it to give an exact
+    /// `size_hint` when they know how their item count relates to the
+    /// number of elements left.
+    pub fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    /// Return the number of remaining elements
+    ///
+    /// This is `remaining` under the name adaptor authors look for: a
+    /// wrapper whose `size_hint` would otherwise lose exactness can rebuild
+    /// it from this count.
+    pub fn exact_len(&self) -> usize {
+        self.remaining()
+    }
+
+    /// Return the size of the remaining elements in bytes
+    ///
+    /// This is the distance between the pointers, so no multiplication
+    /// is needed.
+    pub fn len_bytes(&self) -> usize {
+        self.end as usize - self.ptr as usize
+    }
+
+    /// Return the number of remaining elements, or `None` if the pointers
+    /// are inconsistent: the end is before the start, or their distance
+    /// in bytes is not a multiple of the element size.
+    ///
+    /// This is a defensive check for pointers from outside sources, such as
+    /// FFI. `offset_from` can not be used for it, since its requirements
+    /// (the same allocation, an exact multiple) are what is in doubt, so the
+    /// distance is computed from the addresses instead.
+    pub fn len_checked(&self) -> Option<usize> {
+        let bytes = (self.end as usize).checked_sub(self.ptr as usize)?;
+        if bytes % size_of::<T>() == 0 {
+            Some(bytes / size_of::<T>())
+        } else {
+            None
+        }
+    }
+
+    /// Return the equivalent slice
+    ///
+    /// The slice has the full lifetime `'a` of the data, not that of the
+    /// borrow of `self`, so it can be kept while the iterator is moved or
+    /// advanced further. It aliases the iterator's remaining range; both
+    /// are shared borrows, so that is fine.
+    pub fn as_slice(&self) -> &'a [T] {
+        unsafe {
+            slice::from_raw_parts(self.ptr, self.len())
+        }
+    }
+
+    /// Return the equivalent slice, with the full lifetime `'a`
+    ///
+    /// This is the same as `as_slice`, whose result already outlives the
+    /// borrow of `self`; see there for the aliasing.
+    pub fn remaining_slice(&self) -> &'a [T] {
+        self.as_slice()
+    }
+
+    /// Return the equivalent slice, consuming the iterator
+    pub fn into_slice(self) -> &'a [T] {
+        self.as_slice()
+    }
+
+    /// Return a libcore slice iterator over the remaining elements,
+    /// consuming the iterator
+    pub fn into_core_iter(self) -> CoreSliceIter<'a, T> {
+        self.into_slice().iter()
+    }
+
+    /// Return the remaining elements as a slice of `N`-element arrays,
+    /// and a remainder slice of less than `N` elements.
+    ///
+    /// Panics if `N` is zero.
+    pub fn as_chunks<const N: usize>(&self) -> (&'a [[T; N]], &'a [T]) {
+        assert!(N != 0);
+        let len = self.len();
+        let head_len = len / N;
+        unsafe {
+            let head = slice::from_raw_parts(self.ptr as *const [T; N], head_len);
+            let tail = slice::from_raw_parts(self.ptr.add(head_len * N),
+                                             len - head_len * N);
+            (head, tail)
+        }
+    }
+
+    /// Return the remaining elements viewed as raw bytes
+    ///
+    /// The byte slice covers the same range, so its length is
+    /// `len_bytes()`, that is `len() * size_of::<T>()`.
+    ///
+    /// Unsafe because the caller must ensure that `T` has no padding or
+    /// otherwise uninitialized bytes (it is plain data, like the integer
+    /// and float types or arrays of them).
+    pub unsafe fn as_bytes(&self) -> &'a [u8] {
+        slice::from_raw_parts(self.ptr as *const u8, self.len_bytes())
+    }
+
+    /// Return an iterator over the remaining range reinterpreted as
+    /// elements of type `U`.
+    ///
+    /// Return `SliceIterError::SizeNotMultiple` if the length in bytes is
+    /// not a multiple of the size of `U`, and `SliceIterError::Misaligned`
+    /// if the start pointer is not aligned for `U`.
+    ///
+    /// Unsafe because the caller must ensure that the bytes of the range
+    /// are valid values of type `U` (that is, both types are plain data).
+    ///
+    /// Panics if `U` is a zero-sized type.
+    pub unsafe fn cast<U>(self) -> Result<SliceIter<'a, U>, SliceIterError> {
+        assert!(size_of::<U>() != 0);
+        let bytes = self.len() * size_of::<T>();
+        if bytes % size_of::<U>() != 0 {
+            return Err(SliceIterError::SizeNotMultiple);
+        }
+        if (self.ptr as usize) % align_of::<U>() != 0 {
+            return Err(SliceIterError::Misaligned);
+        }
+        let ptr = self.ptr as *const U;
+        Ok(SliceIter::new(ptr, ptr.add(bytes / size_of::<U>())))
+    }
+
+    /// Split the remaining range into an unaligned prefix of `T`, a middle
+    /// of `U` that is aligned for `U`, and a suffix of `T`.
+    ///
+    /// This has the same guarantees as the slice method `align_to`: the
+    /// middle is as long as possible, and the prefix and suffix are shorter
+    /// than one `U` each.
+    ///
+    /// Unsafe because the caller must ensure that the bytes of the middle
+    /// part are valid values of type `U`.
+    ///
+    /// Panics if `U` is a zero-sized type.
+    pub unsafe fn align_to<U>(&self) -> (SliceIter<'a, T>, SliceIter<'a, U>, SliceIter<'a, T>) {
+        assert!(size_of::<U>() != 0);
+        let (prefix, middle, suffix) = self.as_slice().align_to::<U>();
+        (SliceIter::from(prefix), SliceIter::from(middle), SliceIter::from(suffix))
+    }
+
+    /// Like `align_to`, but with a uniform error for type pairs that do
+    /// not split cleanly.
+    ///
+    /// Return `SliceIterError::SizeNotMultiple` if neither of the sizes of
+    /// `T` and `U` is a multiple of the other, and
+    /// `SliceIterError::Misaligned` if no element of the range can be
+    /// aligned for `U`. Both widening (`u8` to `u32`) and narrowing (`u32`
+    /// to `u8`) reinterpretation is accepted.
+    ///
+    /// Unsafe because the caller must ensure that the bytes of the middle
+    /// part are valid values of type `U`.
+    ///
+    /// Panics if `U` is a zero-sized type.
+    #[allow(clippy::type_complexity)]
+    pub unsafe fn align_to_checked<U>(&self)
+        -> Result<(SliceIter<'a, T>, SliceIter<'a, U>, SliceIter<'a, T>), SliceIterError>
+    {
+        assert!(size_of::<U>() != 0);
+        if size_of::<U>() % size_of::<T>() != 0 &&
+            size_of::<T>() % size_of::<U>() != 0
+        {
+            return Err(SliceIterError::SizeNotMultiple);
+        }
+        // Stepping by elements can only reach addresses that are multiples of
+        // the largest power of two dividing the element size.
+        let stride_align = min(align_of::<U>(), 1 << size_of::<T>().trailing_zeros());
+        if (self.ptr as usize) % stride_align != 0 {
+            return Err(SliceIterError::Misaligned);
+        }
+        Ok(self.align_to())
+    }
+
+    /// Tell the optimizer that the iterator is not empty.
+    ///
+    /// With the `assume` feature, this makes an empty iterator unreachable
+    /// code; without it, this does nothing.
+    ///
+    /// Unsafe because with the `assume` feature it is undefined behavior to
+    /// call this on an empty iterator.
+    #[inline(always)]
+    pub unsafe fn hint_nonempty(&self) {
+        #[cfg(feature = "assume")]
+        {
+            if self.ptr == self.end {
+                std::hint::unreachable_unchecked()
+            }
+        }
+    }
+
+    /// Hint to the processor that the element `distance` positions ahead
+    /// of the start will be read soon.
+    ///
+    /// Does nothing if that element is outside the remaining range, or if
+    /// the target has no prefetch instruction.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_ahead(&self, distance: usize) {
+        if distance < self.len() {
+            unsafe {
+                prefetch_read(self.ptr.add(distance) as *const i8);
+            }
+        }
+    }
+
+    /// Return `true` if the remaining elements are sorted in ascending order.
+    ///
+    /// Empty and single-element ranges are sorted.
+    pub fn is_sorted(&self) -> bool
+        where T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Return `true` if `compare` returns `true` for every pair of
+    /// adjacent remaining elements.
+    ///
+    /// Empty and single-element ranges are sorted.
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+        where F: FnMut(&T, &T) -> bool,
+    {
+        let mut iter = *self;
+        let mut prev = match iter.next() {
+            Some(first) => first,
+            None => return true,
+        };
+        iter.all(move |elt| {
+            let in_order = compare(prev, elt);
+            prev = elt;
+            in_order
+        })
+    }
+
+    /// Return the index of the partition point of the remaining elements
+    /// according to `pred`: the index of the first element for which `pred`
+    /// returns `false`.
+    ///
+    /// The remaining elements must be partitioned, with all elements where
+    /// `pred` is `true` coming first. Like the rest of the iterator's
+    /// indices, the result is relative to the current start.
+    pub fn partition_point<F>(&self, pred: F) -> usize
+        where F: FnMut(&T) -> bool,
+    {
+        self.as_slice().partition_point(pred)
+    }
+
+    /// Binary search the remaining elements, which must be sorted, for `x`.
+    ///
+    /// Return `Ok(index)` of a matching element, or `Err(index)` where `x`
+    /// could be inserted to maintain the sort order. Indices are relative to
+    /// the current start.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+        where T: Ord,
+    {
+        self.as_slice().binary_search(x)
+    }
+
+    /// Search for the first element that satisfies `predicate`, and return
+    /// a pointer to it.
+    ///
+    /// Like `find`, this consumes the elements up to and including the
+    /// match, or the whole iterator if there is no match.
+    pub fn find_ptr<F>(&mut self, mut predicate: F) -> Option<*const T>
+        where F: FnMut(&T) -> bool,
+    {
+        let found = self.fold_while(false, move |_, elt| {
+            if predicate(elt) {
+                FoldWhile::Done(true)
+            } else {
+                FoldWhile::Continue(false)
+            }
+        });
+        // Derive the pointer from `self.ptr`, which now points one past the
+        // match, rather than from `elt`: a pointer made from the `&T` would
+        // only be valid for that one element.
+        if found {
+            unsafe { Some(self.ptr.sub(1)) }
+        } else {
+            None
+        }
+    }
+
+    /// Return the index of the element that `p` points to, or `None` if
+    /// `p` does not point to the start of one of the remaining elements.
+    ///
+    /// This is the inverse of getting a pointer with `find_ptr`.
+    pub fn index_of_ptr(&self, p: *const T) -> Option<usize> {
+        if p < self.ptr || p >= self.end {
+            return None;
+        }
+        let bytes = p as usize - self.ptr as usize;
+        if bytes % size_of::<T>() == 0 {
+            Some(bytes / size_of::<T>())
+        } else {
+            None
+        }
+    }
+
+    /// Search for the first element that satisfies `predicate`, and return
+    /// its offset in bytes from the current start.
+    ///
+    /// This is the index that `position` returns, times the element size.
+    /// Like `position`, this consumes the elements up to and including the
+    /// match, or the whole iterator if there is no match.
+    pub fn byte_position<F>(&mut self, predicate: F) -> Option<usize>
+        where F: FnMut(&T) -> bool,
+    {
+        let start = self.ptr;
+        self.find_ptr(predicate).map(|p| p as usize - start as usize)
+    }
+
+    /// Search for the first element that satisfies `predicate`, and return
+    /// its index and a reference to it.
+    ///
+    /// The index is counted from the current start. Like `find`, this
+    /// consumes the elements up to and including the match, or the whole
+    /// iterator if there is no match.
+    pub fn find_position<F>(&mut self, mut predicate: F) -> Option<(usize, &'a T)>
+        where F: FnMut(&T) -> bool,
+    {
+        let mut index = 0;
+        self.fold_while(None, move |_, elt| {
+            if predicate(elt) {
+                FoldWhile::Done(Some((index, elt)))
+            } else {
+                index += 1;
+                FoldWhile::Continue(None)
+            }
+        })
+    }
+
+    /// Search for the first element where `f` returns `true`, threading a
+    /// mutable state through the search, and return its index together
+    /// with the final state.
+    ///
+    /// The index is counted from the current start. Like `find_position`,
+    /// this consumes the elements up to and including the match, or the
+    /// whole iterator if there is no match.
+    ///
+    /// ```
+    /// use rawslice::SliceIter;
+    ///
+    /// // find the bracket that closes the first one
+    /// let text = b"(a(b)c)d)";
+    /// let mut iter = SliceIter::from(&text[..]);
+    /// let found = iter.find_with_state(0, |depth, &c| {
+    ///     match c {
+    ///         b'(' => *depth += 1,
+    ///         b')' => *depth -= 1,
+    ///         _ => {}
+    ///     }
+    ///     *depth == 0
+    /// });
+    /// assert_eq!(found, Some((6, 0)));
+    /// assert_eq!(iter.as_slice(), b"d)");
+    /// ```
+    pub fn find_with_state<St, F>(&mut self, init: St, mut f: F) -> Option<(usize, St)>
+        where F: FnMut(&mut St, &T) -> bool,
+    {
+        let mut index = 0;
+        let (state, found) = self.fold_reporting(init, |mut state, elt| {
+            if f(&mut state, elt) {
+                FoldWhile::Done(state)
+            } else {
+                index += 1;
+                FoldWhile::Continue(state)
+            }
+        });
+        if found {
+            Some((index, state))
+        } else {
+            None
+        }
+    }
+
+    /// Count the elements that satisfy `predicate`, and return that count
+    /// together with the total number of elements.
+    ///
+    /// `count` on a `SliceIter` is just `len()`, but after `filter` it
+    /// becomes a full pass; use this when you need both numbers, for
+    /// example to compute a ratio, and get them in one unrolled pass.
+    ///
+    /// ```
+    /// use rawslice::SliceIter;
+    ///
+    /// let data = [1, 2, 3, 4, 5, 6, 7];
+    /// let iter = SliceIter::from(&data[..]);
+    /// assert_eq!(iter.counted_filter(|x| x % 2 == 0), (3, 7));
+    /// ```
+    pub fn counted_filter<F>(self, mut predicate: F) -> (usize, usize)
+        where F: FnMut(&T) -> bool,
+    {
+        let total = self.len();
+        let matches = self.fold(0, move |n, elt| n + predicate(elt) as usize);
+        (matches, total)
+    }
+
+    /// Return the sum of the remaining elements, computed in several lanes
+    /// that are added together at the end.
+    ///
+    /// See `MultiLaneSum` for how the result can differ from `sum`.
+    #[cfg(feature = "multilane")]
+    pub fn multilane_sum(self) -> T
+        where T: MultiLaneSum,
+    {
+        T::multilane_sum(self.as_slice())
+    }
+
+    /// Return `true` if the remaining elements of `self` and `other` have
+    /// the same length and `eq` returns `true` for each pair of elements.
+    ///
+    /// Stops at the first pair that is not equal.
+    pub fn eq_by<'b, U, F>(mut self, mut other: SliceIter<'b, U>, mut eq: F) -> bool
+        where F: FnMut(&T, &U) -> bool,
+    {
+        if self.len() != other.len() {
+            return false;
+        }
+        self.all(move |elt| unsafe {
+            eq(elt, other.next_unchecked())
+        })
+    }
+
+    /// Return `true` if the remaining elements of `self` and `other` are
+    /// equal.
+    ///
+    /// Stops at the first pair that is not equal.
+    pub fn eq_iter<'b, U>(self, other: SliceIter<'b, U>) -> bool
+        where T: PartialEq<U>,
+    {
+        self.eq_by(other, |a, b| a == b)
+    }
+
+    /// Compare the remaining elements of `self` and `other`
+    /// lexicographically.
+    ///
+    /// Stops at the first pair that is not equal.
+    pub fn cmp_iter(&self, other: &SliceIter<T>) -> Ordering
+        where T: Ord,
+    {
+        match self.partial_cmp_by(other, |a, b| Some(a.cmp(b))) {
+            Some(ord) => ord,
+            None => unreachable!(),
+        }
+    }
+
+    /// Compare the remaining elements of `self` and `other`
+    /// lexicographically, or return `None` if a pair of elements is not
+    /// comparable.
+    ///
+    /// Stops at the first pair that is not equal.
+    pub fn partial_cmp_iter(&self, other: &SliceIter<T>) -> Option<Ordering>
+        where T: PartialOrd,
+    {
+        self.partial_cmp_by(other, T::partial_cmp)
+    }
+
+    fn partial_cmp_by<F>(&self, other: &SliceIter<T>, mut cmp: F) -> Option<Ordering>
+        where F: FnMut(&T, &T) -> Option<Ordering>,
+    {
+        let len = min(self.len(), other.len());
+        let mut a = self.take_raw(len);
+        let mut b = *other;
+        let ord = a.fold_while(Some(Ordering::Equal), move |_, elt| unsafe {
+            match cmp(elt, b.next_unchecked()) {
+                Some(Ordering::Equal) => FoldWhile::Continue(Some(Ordering::Equal)),
+                ord => FoldWhile::Done(ord),
+            }
+        });
+        match ord {
+            Some(Ordering::Equal) => Some(self.len().cmp(&other.len())),
+            ord => ord,
+        }
+    }
+
+    /// Return `true` if the remaining elements start with `needle`, using
+    /// `eq` to compare elements.
+    ///
+    /// An empty `needle` always matches.
+    ///
+    /// ```
+    /// use rawslice::SliceIter;
+    ///
+    /// let iter = SliceIter::from(&b"Hello, world"[..]);
+    /// assert!(iter.starts_with_by(b"hELLO", |a, b| a.eq_ignore_ascii_case(b)));
+    /// ```
+    pub fn starts_with_by<F>(&self, needle: &[T], eq: F) -> bool
+        where F: FnMut(&T, &T) -> bool,
+    {
+        needle.len() <= self.len() &&
+            self.take_raw(needle.len()).eq_by(SliceIter::from(needle), eq)
+    }
+
+    /// Return the index of the first minimum element, or `None` if the
+    /// iterator is empty.
+    pub fn position_min(&self) -> Option<usize>
+        where T: Ord,
+    {
+        self.position_best(|elt, best| elt < best)
+    }
+
+    /// Return the index of the first maximum element, or `None` if the
+    /// iterator is empty.
+    pub fn position_max(&self) -> Option<usize>
+        where T: Ord,
+    {
+        self.position_best(|elt, best| elt > best)
+    }
+
+    /// Return the index of the last element with the maximum key, or `None`
+    /// if the iterator is empty.
+    ///
+    /// `f` is called once per element. Ties are broken like itertools'
+    /// `position_max_by_key`, which picks the last maximum; notice that this
+    /// differs from `position_max`, which picks the first.
+    pub fn position_max_by_key<K, F>(&self, mut f: F) -> Option<usize>
+        where K: Ord,
+              F: FnMut(&T) -> K,
+    {
+        self.position_best_key(&mut f, |key, best| key >= best)
+    }
+
+    /// Return the index of the first element with the minimum key, or
+    /// `None` if the iterator is empty.
+    ///
+    /// `f` is called once per element. Ties are broken like itertools'
+    /// `position_min_by_key`, which picks the first minimum.
+    pub fn position_min_by_key<K, F>(&self, mut f: F) -> Option<usize>
+        where K: Ord,
+              F: FnMut(&T) -> K,
+    {
+        self.position_best_key(&mut f, |key, best| key < best)
+    }
+
+    /// Return the last element with the maximum key, together with its key,
+    /// or `None` if the iterator is empty.
+    ///
+    /// `f` is called once per element. Ties are broken like
+    /// `Iterator::max_by_key`.
+    pub fn max_by_key_with<K, F>(self, mut f: F) -> Option<(K, &'a T)>
+        where K: Ord,
+              F: FnMut(&T) -> K,
+    {
+        let mut iter = self;
+        let first = iter.next()?;
+        Some(iter.fold((f(first), first), move |best, elt| {
+            let key = f(elt);
+            if key >= best.0 { (key, elt) } else { best }
+        }))
+    }
+
+    /// Return the first element with the minimum key, together with its
+    /// key, or `None` if the iterator is empty.
+    ///
+    /// `f` is called once per element. Ties are broken like
+    /// `Iterator::min_by_key`.
+    pub fn min_by_key_with<K, F>(self, mut f: F) -> Option<(K, &'a T)>
+        where K: Ord,
+              F: FnMut(&T) -> K,
+    {
+        let mut iter = self;
+        let first = iter.next()?;
+        Some(iter.fold((f(first), first), move |best, elt| {
+            let key = f(elt);
+            if key < best.0 { (key, elt) } else { best }
+        }))
+    }
+
+    /// Return the minimum and the maximum element in one pass, or `None` if
+    /// the iterator is empty.
+    ///
+    /// The elements are compared in pairs, which needs three comparisons
+    /// per two elements instead of four. Ties are broken like
+    /// `Iterator::min` (the first minimum) and `Iterator::max` (the last
+    /// maximum).
+    pub fn min_max(self) -> Option<(&'a T, &'a T)>
+        where T: Ord,
+    {
+        let mut iter = self;
+        let first = iter.next()?;
+        let (pairs, rest) = iter.as_chunks::<2>();
+        let (mut min, mut max) = (first, first);
+        for pair in pairs {
+            let (lo, hi) = if pair[1] < pair[0] {
+                (&pair[1], &pair[0])
+            } else {
+                (&pair[0], &pair[1])
+            };
+            if lo < min {
+                min = lo;
+            }
+            if hi >= max {
+                max = hi;
+            }
+        }
+        if let Some(last) = rest.first() {
+            if last < min {
+                min = last;
+            }
+            if last >= max {
+                max = last;
+            }
+        }
+        Some((min, max))
+    }
+
+    /// Return the index of the first element that no later element is
+    /// `better` than.
+    fn position_best<F>(&self, mut better: F) -> Option<usize>
+        where F: FnMut(&T, &T) -> bool,
+    {
+        let mut iter = *self;
+        let first = iter.next()?;
+        let (_, best_index, _) = iter.fold((first, 0, 1), |(best, best_index, index), elt| {
+            if better(elt, best) {
+                (elt, index, index + 1)
+            } else {
+                (best, best_index, index + 1)
+            }
+        });
+        Some(best_index)
+    }
+
+    /// Return the index of the element whose key no later key is `better`
+    /// than, computing each key once.
+    fn position_best_key<K, F, G>(&self, f: &mut F, mut better: G) -> Option<usize>
+        where F: FnMut(&T) -> K,
+              G: FnMut(&K, &K) -> bool,
+    {
+        let mut iter = *self;
+        let first = f(iter.next()?);
+        let (_, best_index, _) = iter.fold((first, 0, 1), |(best, best_index, index), elt| {
+            let key = f(elt);
+            if better(&key, &best) {
+                (key, index, index + 1)
+            } else {
+                (best, best_index, index + 1)
+            }
+        });
+        Some(best_index)
+    }
+
+    /// Call `f` on each element from the back, stopping at the first
+    /// `ControlFlow::Break` and returning it.
+    ///
+    /// On an early stop, the elements from the back up to and including the
+    /// one that broke are consumed, and the rest can still be iterated from
+    /// either end.
+    pub fn rtry_for_each<B, F>(&mut self, mut f: F) -> ControlFlow<B>
+        where F: FnMut(&'a T) -> ControlFlow<B>,
+    {
+        self.rfold_while(ControlFlow::Continue(()), move |_, elt| {
+            match f(elt) {
+                ControlFlow::Continue(()) => FoldWhile::Continue(ControlFlow::Continue(())),
+                done => FoldWhile::Done(done),
+            }
+        })
+    }
+
+    /// Reduce the elements to one, stopping at the first error from `f`.
+    ///
+    /// The first element is cloned to start the accumulator, and `f`
+    /// combines it with each following element. Return `Ok(None)` if the
+    /// iterator is empty. On an error, the elements up to and including the
+    /// one that failed are consumed.
+    ///
+    /// ```
+    /// use rawslice::SliceIter;
+    ///
+    /// let data = [1u8, 2, 3, 250];
+    /// let mut iter = SliceIter::from(&data[..3]);
+    /// assert_eq!(iter.try_reduce(|a, &b| a.checked_add(b).ok_or(())), Ok(Some(6)));
+    ///
+    /// let mut iter = SliceIter::from(&data[..]);
+    /// assert_eq!(iter.try_reduce(|a, &b| a.checked_add(b).ok_or(())), Err(()));
+    /// ```
+    pub fn try_reduce<E, F>(&mut self, mut f: F) -> Result<Option<T>, E>
+        where T: Clone,
+              F: FnMut(T, &'a T) -> Result<T, E>,
+    {
+        let first = match self.next() {
+            None => return Ok(None),
+            Some(first) => first.clone(),
+        };
+        self.fold_while(Ok(Some(first)), move |accum, elt| {
+            match accum {
+                Ok(Some(accum)) => match f(accum, elt) {
+                    Ok(accum) => FoldWhile::Continue(Ok(Some(accum))),
+                    Err(e) => FoldWhile::Done(Err(e)),
+                },
+                done => FoldWhile::Done(done),
+            }
+        })
+    }
+
+    /// Copy the remaining elements into `dst`.
+    ///
+    /// Panics if the length of `dst` is not equal to the length of the
+    /// iterator.
+    pub fn copy_to_slice(self, dst: &mut [T])
+        where T: Copy,
+    {
+        assert_eq!(self.len(), dst.len(), "destination length does not match");
+        unsafe {
+            ptr::copy_nonoverlapping(self.ptr, dst.as_mut_ptr(), dst.len());
+        }
+    }
+
+    /// Copy the remaining elements to `dst`.
+    ///
+    /// Unsafe because `dst` must be valid for writing `len()` elements, and
+    /// the destination must not overlap the remaining range, which is
+    /// borrowed as shared. For an overlapping copy within one buffer, use
+    /// `SliceIterMut::copy_within`.
+    pub unsafe fn copy_to_ptr(self, dst: *mut T)
+        where T: Copy,
+    {
+        ptr::copy_nonoverlapping(self.ptr, dst, self.len());
+    }
+
+    /// Append clones of the remaining elements to `out`.
+    ///
+    /// Reserves space for all elements up front.
+    #[cfg(feature = "alloc")]
+    pub fn extend_into(self, out: &mut Vec<T>)
+        where T: Clone,
+    {
+        out.reserve(self.len());
+        let dst = out.as_mut_ptr();
+        let mut len = out.len();
+        self.fold((), move |(), elt| unsafe {
+            dst.add(len).write(elt.clone());
+            len += 1;
+            out.set_len(len);
+        });
+    }
+
+    /// Return a new vector holding clones of the remaining elements.
+    ///
+    /// This allocates; the vector is created with capacity `len()` and
+    /// filled using `extend_into`.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T>
+        where T: Clone,
+    {
+        let mut out = Vec::with_capacity(self.len());
+        self.extend_into(&mut out);
+        out
+    }
+
+    /// Return a new boxed slice holding clones of the remaining elements.
+    ///
+    /// This allocates exactly `len()` elements once; it is filled like
+    /// `to_vec`, and since the capacity matches the length, the conversion
+    /// to a box does not reallocate.
+    #[cfg(feature = "alloc")]
+    pub fn to_boxed_slice(&self) -> Box<[T]>
+        where T: Clone,
+    {
+        self.to_vec().into_boxed_slice()
+    }
+
+    /// Return a new vector holding clones of the remaining elements with a
+    /// clone of `sep` between each pair of them, like `[T]::join` with a
+    /// single element separator.
+    ///
+    /// This allocates; the vector is created with the exact capacity.
+    #[cfg(feature = "alloc")]
+    pub fn join_into(self, sep: &T) -> Vec<T>
+        where T: Clone,
+    {
+        let mut iter = self;
+        let mut out = Vec::with_capacity((2 * iter.len()).saturating_sub(1));
+        if let Some(first) = iter.next() {
+            out.push(first.clone());
+            iter.fold((), |(), elt| {
+                out.push(sep.clone());
+                out.push(elt.clone());
+            });
+        }
+        out
+    }
+
+    /// Return a new vector holding clones of the remaining elements, with
+    /// `to` in place of each element equal to `from`.
+    ///
+    /// This allocates; the vector is created with capacity `len()` and
+    /// filled with the unrolled `fold`.
+    #[cfg(feature = "alloc")]
+    pub fn replace_into(self, from: &T, to: &T) -> Vec<T>
+        where T: Clone + PartialEq,
+    {
+        let mut out = Vec::with_capacity(self.len());
+        self.fold((), |(), elt| {
+            out.push(if elt == from { to.clone() } else { elt.clone() });
+        });
+        out
+    }
+
+    /// Return clones of the remaining elements, split into those that
+    /// satisfy `pred` and those that don't, in a pair of vectors.
+    ///
+    /// This allocates. Both vectors are created with capacity `len()`, since
+    /// the split is not known up front, so together they over-reserve by
+    /// `len()` elements; in exchange, no push reallocates. The elements are
+    /// visited once, with the unrolled `fold`.
+    #[cfg(feature = "alloc")]
+    pub fn partition_vec<F>(self, mut pred: F) -> (Vec<T>, Vec<T>)
+        where T: Clone,
+              F: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+        let init = (Vec::with_capacity(len), Vec::with_capacity(len));
+        self.fold(init, move |(mut yes, mut no), elt| {
+            if pred(elt) {
+                yes.push(elt.clone());
+            } else {
+                no.push(elt.clone());
+            }
+            (yes, no)
+        })
+    }
+
+    /// Copy out the next `N` elements as an array, or return `None` without
+    /// consuming anything if fewer than `N` remain.
+    pub fn collect_array<const N: usize>(&mut self) -> Option<[T; N]>
+        where T: Copy,
+    {
+        if self.len() < N {
+            return None;
+        }
+        unsafe {
+            let arr = ptr::read(self.ptr as *const [T; N]);
+            self.ptr = self.ptr.add(N);
+            Some(arr)
+        }
+    }
+
+    /// Return references to the next `N` elements, or the iterator itself,
+    /// unchanged, in the error if fewer than `N` remain.
+    ///
+    /// `N` must be non-zero; this is checked at compile time.
+    pub fn next_chunk<const N: usize>(&mut self) -> Result<[&'a T; N], Self> {
+        const { assert!(N != 0, "chunk size must be non-zero") };
+        if self.len() < N {
+            return Err(*self);
+        }
+        let start = self.ptr;
+        unsafe {
+            self.ptr = start.add(N);
+            Ok(array::from_fn(|i| &*start.add(i)))
+        }
+    }
+
+    /// Return the next `k` elements as a slice and step past them, or
+    /// `None` if fewer than `k` remain.
+    ///
+    /// On `None`, nothing is consumed. This is the read primitive for
+    /// fixed-width fields.
+    pub fn take_exact(&mut self, k: usize) -> Option<&'a [T]> {
+        if self.len() < k {
+            return None;
+        }
+        unsafe {
+            let head = slice::from_raw_parts(self.ptr, k);
+            self.ptr = self.ptr.add(k);
+            Some(head)
+        }
+    }
+
+    /// Return the next iterator element, without checking if the end is reached
+    #[inline]
+    pub unsafe fn next_unchecked(&mut self) -> <Self as Iterator>::Item {
+        &*self.ptr.post_inc()
+    }
+
+    /// Return the next iterator element from the back, without checking if
+    /// the end is reached
+    ///
+    /// Unsafe because the iterator must not be empty.
+    #[inline]
+    pub unsafe fn next_back_unchecked(&mut self) -> <Self as Iterator>::Item {
+        &*self.end.pre_dec()
+    }
+
+    /// Return a reference to the element at `i`.
+    ///
+    /// In debug builds, panics if the start pointer is misaligned for `T`.
+    pub unsafe fn get_unchecked(&self, i: usize) -> &T {
+        check_aligned(self.ptr);
+        &*self.ptr.add(i)
+    }
+
+    /// Split the iterator in two at index `i`; the first half has the
+    /// elements before `i` and the second half the rest.
+    ///
+    /// Panics if `i` is greater than the length.
+    pub fn split_at(self, i: usize) -> (Self, Self) {
+        assert!(i <= self.len());
+        unsafe {
+            let mid = self.ptr.add(i);
+            (SliceIter::new(self.ptr, mid), SliceIter::new(mid, self.end))
+        }
+    }
+
+    /// Split the iterator in two at index `i`, like `split_at`, or return
+    /// `None` if `i` is greater than the length.
+    pub fn split_at_checked(self, i: usize) -> Option<(Self, Self)> {
+        if i <= self.len() {
+            Some(self.split_at(i))
+        } else {
+            None
+        }
+    }
+
+    /// Return an iterator over the elements in the index range `r`, or
+    /// `None` if the range is decreasing or extends past the end.
+    pub fn subslice_checked(self, r: Range<usize>) -> Option<Self> {
+        let (_, rest) = self.split_at_checked(r.start)?;
+        let (mid, _) = rest.split_at_checked(r.end.checked_sub(r.start)?)?;
+        Some(mid)
+    }
+
+    /// Return an iterator over the elements in `range`, which can be any
+    /// kind of index range, or `None` if it is decreasing or extends past
+    /// the end.
+    ///
+    /// This is `subslice_checked` for all the range types.
+    pub fn get_range<R>(&self, range: R) -> Option<Self>
+        where R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i.checked_add(1)?,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => self.len(),
+        };
+        self.subslice_checked(start..end)
+    }
+
+    /// Join two adjacent iterators into one, or return `None` if `self`
+    /// does not end where `other` starts.
+    ///
+    /// This is the inverse of `split_at`. The ranges must be contiguous
+    /// parts of the same allocation, which holds for the result of splitting
+    /// one iterator; pointers that are merely equal in value but belong to
+    /// different allocations must not be joined.
+    pub fn try_join(self, other: Self) -> Option<Self> {
+        if self.end == other.ptr {
+            unsafe {
+                Some(SliceIter::new(self.ptr, other.end))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Skip `n` elements and return the next one, like `nth`, or the number
+    /// of elements skipped if the iterator ran out first.
+    ///
+    /// On an error, the iterator is left empty.
+    pub fn split_off_nth(&mut self, n: usize) -> Result<&'a T, usize> {
+        let len = self.len();
+        if n < len {
+            unsafe {
+                self.ptr = self.ptr.add(n);
+                Ok(self.next_unchecked())
+            }
+        } else {
+            self.ptr = self.end;
+            Err(len)
+        }
+    }
+
+    /// Skip `n` elements from the back and return the next one from the
+    /// back, like `nth_back`, or the number of elements skipped if the
+    /// iterator ran out first.
+    ///
+    /// On an error, the iterator is left empty.
+    pub fn split_off_nth_back(&mut self, n: usize) -> Result<&'a T, usize> {
+        let len = self.len();
+        if n < len {
+            unsafe {
+                self.end = self.end.sub(n + 1);
+                Ok(&*self.end)
+            }
+        } else {
+            self.end = self.ptr;
+            Err(len)
+        }
+    }
+
+    /// Skip `n` elements from the back and return the next one from the
+    /// back, or `None` if the iterator ran out first.
+    ///
+    /// This is `nth_back` in O(1) time. Like the libcore slice iterator,
+    /// the iterator is left empty on a miss.
+    pub fn nth_back_checked(&mut self, n: usize) -> Option<&'a T> {
+        self.split_off_nth_back(n).ok()
+    }
+
+    /// Return a reference to the element at index `i`, or an error with
+    /// the index and the current length if it is out of bounds.
+    ///
+    /// This is the non-panicking counterpart to `iter[i]`.
+    pub fn try_index(&self, i: usize) -> Result<&'a T, IndexError> {
+        let len = self.len();
+        if i < len {
+            unsafe {
+                Ok(&*self.ptr.add(i))
+            }
+        } else {
+            Err(IndexError { index: i, len })
+        }
+    }
+
+    /// Drop `front` elements from the front and `back` elements from the
+    /// back.
+    ///
+    /// If there are fewer than `front + back` elements, this clamps: the
+    /// elements from the front are dropped first, and the iterator ends up
+    /// empty rather than panicking.
+    pub fn shrink(&mut self, front: usize, back: usize) {
+        let front = min(front, self.len());
+        unsafe {
+            self.ptr = self.ptr.add(front);
+            let back = min(back, self.len());
+            self.end = self.end.sub(back);
+        }
+    }
+
+    /// Limit the remaining range to at most the first `new_len` elements.
+    ///
+    /// This is the in-place counterpart to `take_raw`; it never extends the
+    /// range.
+    pub fn truncate(&mut self, new_len: usize) {
+        let len = min(new_len, self.len());
+        unsafe {
+            self.end = self.ptr.add(len);
+        }
+    }
+
+    /// Return an iterator over at most the first `n` elements.
+    ///
+    /// Like `Iterator::take`, but this returns a `SliceIter` over the
+    /// shortened range.
+    pub fn take_raw(self, n: usize) -> Self {
+        let n = min(n, self.len());
+        self.split_at(n).0
+    }
+
+    /// Return an iterator with the first `n` elements skipped, or an empty
+    /// iterator if there are fewer than `n`.
+    ///
+    /// Like `Iterator::skip`, but this returns a `SliceIter` over the
+    /// shortened range.
+    pub fn skip_raw(self, n: usize) -> Self {
+        let n = min(n, self.len());
+        self.split_at(n).1
+    }
+
+    /// Advance past the longest prefix of elements that satisfy `pred`,
+    /// and return an iterator over that prefix.
+    ///
+    /// The first element that does not satisfy `pred` is not consumed.
+    pub fn take_while_raw<F>(&mut self, mut pred: F) -> Self
+        where F: FnMut(&T) -> bool,
+    {
+        let start = self.ptr;
+        let mut rest = *self;
+        let mid = rest.find_ptr(move |elt| !pred(elt)).unwrap_or(self.end);
+        unsafe {
+            self.ptr = mid;
+            SliceIter::new(start, mid)
+        }
+    }
+
+    /// Advance past the longest prefix of elements that satisfy `pred`.
+    ///
+    /// The first element that does not satisfy `pred` is not consumed.
+    pub fn skip_while_raw<F>(&mut self, pred: F)
+        where F: FnMut(&T) -> bool,
+    {
+        self.take_while_raw(pred);
+    }
+
+    /// Return the iterator with the leading elements that match `pred`
+    /// removed.
+    pub fn trim_start_matches<F>(mut self, pred: F) -> Self
+        where F: FnMut(&T) -> bool,
+    {
+        self.skip_while_raw(pred);
+        self
+    }
+
+    /// Return the iterator with the trailing elements that match `pred`
+    /// removed.
+    pub fn trim_end_matches<F>(mut self, mut pred: F) -> Self
+        where F: FnMut(&T) -> bool,
+    {
+        let mut rest = self;
+        let len = rest.rposition(move |elt| !pred(elt)).map_or(0, |i| i + 1);
+        unsafe {
+            self.end = self.ptr.add(len);
+        }
+        self
+    }
+
+    /// Return the iterator with the leading and trailing elements that
+    /// match `pred` removed.
+    pub fn trim_matches<F>(self, mut pred: F) -> Self
+        where F: FnMut(&T) -> bool,
+    {
+        self.trim_start_matches(&mut pred).trim_end_matches(pred)
+    }
+
+    /// Return the first element and an iterator of the rest, or `None` if
+    /// the iterator is empty.
+    pub fn split_first(self) -> Option<(&'a T, Self)> {
+        if self.ptr != self.end {
+            unsafe {
+                Some(self.split_first_unchecked())
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Return the last element and an iterator of the rest, or `None` if
+    /// the iterator is empty.
+    pub fn split_last(self) -> Option<(&'a T, Self)> {
+        if self.ptr != self.end {
+            unsafe {
+                Some(self.split_last_unchecked())
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Return the first element and an iterator of the rest, without
+    /// checking if the iterator is empty.
+    ///
+    /// Unsafe because the iterator must not be empty.
+    #[inline]
+    pub unsafe fn split_first_unchecked(mut self) -> (&'a T, Self) {
+        let first = self.next_unchecked();
+        (first, self)
+    }
+
+    /// Return the last element and an iterator of the rest, without
+    /// checking if the iterator is empty.
+    ///
+    /// Unsafe because the iterator must not be empty.
+    #[inline]
+    pub unsafe fn split_last_unchecked(mut self) -> (&'a T, Self) {
+        let last = self.next_back_unchecked();
+        (last, self)
+    }
+
+    /// Split at the first element that matches `pred`, returning the
+    /// elements before it as a slice and an iterator over the elements
+    /// after it. The matched element is not included.
+    ///
+    /// Return `None` if no element matches.
+    pub fn split_once<F>(self, pred: F) -> Option<(&'a [T], Self)>
+        where F: FnMut(&T) -> bool,
+    {
+        let mut rest = self;
+        let i = rest.position(pred)?;
+        unsafe {
+            Some((self.as_slice().get_unchecked(..i), rest))
+        }
+    }
+
+    /// Split at the last element that matches `pred`, returning an iterator
+    /// over the elements before it and the elements after it as a slice.
+    /// The matched element is not included.
+    ///
+    /// Return `None` if no element matches.
+    pub fn rsplit_once<F>(self, pred: F) -> Option<(Self, &'a [T])>
+        where F: FnMut(&T) -> bool,
+    {
+        let mut head = self;
+        let i = head.rposition(pred)?;
+        unsafe {
+            head.end = self.ptr.add(i);
+            Some((head, self.as_slice().get_unchecked(i + 1..)))
+        }
+    }
+
+    /// Return an iterator over the subslices separated by elements that
+    /// match `pred`. The matched elements are not included.
+    ///
+    /// Like the slice method `split`, a match at the start or end produces
+    /// an empty subslice there.
+    pub fn split<F>(self, pred: F) -> Split<'a, T, F>
+        where F: FnMut(&T) -> bool,
+    {
+        Split::new(self, pred)
+    }
+
+    /// Return an iterator over the subslices separated by elements that
+    /// match `pred`, where each subslice ends with its matched element.
+    ///
+    /// When the last element matches, there is no empty subslice after it.
+    pub fn split_inclusive<F>(self, pred: F) -> SplitInclusive<'a, T, F>
+        where F: FnMut(&T) -> bool,
+    {
+        SplitInclusive::new(self, pred)
+    }
+
+    /// Return an iterator over the subslices separated by elements that
+    /// match `pred`, like `split`, but without the empty subslice after a
+    /// match at the end.
+    ///
+    /// This is the slice version of `str::split_terminator`. An empty
+    /// range produces no subslices.
+    pub fn split_terminator<F>(self, pred: F) -> SplitTerminator<'a, T, F>
+        where F: FnMut(&T) -> bool,
+    {
+        SplitTerminator::new(self, pred)
+    }
+
+    /// Return an iterator over the non-empty subslices separated by
+    /// elements that match `pred`.
+    ///
+    /// Runs of matches are skipped as one, like `str::split_whitespace`
+    /// does for whitespace.
+    pub fn split_skip_empty<F>(self, pred: F) -> SplitSkipEmpty<'a, T, F>
+        where F: FnMut(&T) -> bool,
+    {
+        SplitSkipEmpty::new(self, pred)
+    }
+
+    /// Return an iterator over the subslices separated by elements that
+    /// match `pred`, starting from the back.
+    pub fn rsplit<F>(self, pred: F) -> RSplit<'a, T, F>
+        where F: FnMut(&T) -> bool,
+    {
+        RSplit::new(self, pred)
+    }
+
+    /// Return an iterator over at most `n` subslices separated by elements
+    /// that match `pred`, starting from the back.
+    ///
+    /// After `n - 1` splits, the rest of the range is the last subslice.
+    pub fn rsplitn<F>(self, n: usize, pred: F) -> RSplitN<'a, T, F>
+        where F: FnMut(&T) -> bool,
+    {
+        RSplitN::new(self, n, pred)
+    }
+
+    /// Return an iterator over the maximal runs of elements where each
+    /// consecutive pair `a, b` in the run satisfies `same(a, b)`.
+    pub fn group_by<F>(self, same: F) -> GroupBy<'a, T, F>
+        where F: FnMut(&T, &T) -> bool,
+    {
+        GroupBy::new(self, same)
+    }
 
-impl<'a, T> SliceIter<'a, T> {
-    /// Create a new slice iterator
+    /// Return an iterator over the runs of elements grouped like
+    /// `group_by`, as pairs of the first element and the length of the run.
+    pub fn run_length<F>(self, same: F) -> RunLength<'a, T, F>
+        where F: FnMut(&T, &T) -> bool,
+    {
+        RunLength::new(self, same)
+    }
+
+    /// Return an iterator over the first element of each run of equal
+    /// adjacent elements.
     ///
-    /// See also ``SliceIter::from, SliceIter::default``.
+    /// This skips the duplicates without modifying the data.
+    pub fn dedup(self) -> Dedup<'a, T>
+        where T: PartialEq,
+    {
+        Dedup::new(self)
+    }
+
+    /// Return an iterator over the first element of each run of adjacent
+    /// elements whose keys are equal.
     ///
-    /// Panics if `T` is a zero-sized type. That case is not supported.
-    #[inline]
-    pub unsafe fn new(start: *const T, end: *const T) -> Self {
-        assert!(size_of::<T>() != 0);
-        SliceIter {
-            ptr: start,
-            end: end,
-            ty: PhantomData,
-        }
+    /// The key is computed once per element.
+    pub fn dedup_by_key<K, F>(self, key: F) -> DedupByKey<'a, T, F, K>
+        where F: FnMut(&T) -> K,
+              K: PartialEq,
+    {
+        DedupByKey::new(self, key)
     }
 
-    /// Return the start pointer
-    pub fn start(&self) -> *const T {
-        self.ptr
+    /// Return an iterator over every `step`th element, starting with the
+    /// first.
+    ///
+    /// Like `Iterator::step_by`, but this steps the start pointer directly.
+    ///
+    /// Panics if `step` is zero.
+    pub fn step_by_raw(self, step: usize) -> StepByRaw<'a, T> {
+        StepByRaw::new(self, step)
     }
 
-    /// Return the end pointer
-    pub fn end(&self) -> *const T {
-        self.end
+    /// Return an iterator over the elements and their indices.
+    ///
+    /// The index of an element is its distance from the start of the
+    /// iterator at the time this method is called, so it is the same from
+    /// either end.
+    pub fn enumerate_from_start(self) -> EnumerateRaw<'a, T> {
+        EnumerateRaw::new(self)
     }
 
-    /// Return mutable reference to the start pointer
+    /// Return an iterator over the elements repeated `times` times.
     ///
-    /// Unsafe because it is easy to violate memory safety by setting
-    /// the pointer outside the data's valid range.
-    pub unsafe fn start_mut(&mut self) -> &mut *const T {
-        &mut self.ptr
+    /// Unlike `Iterator::cycle`, this ends; it is empty if `times` is zero
+    /// or the iterator is empty.
+    pub fn cycle_n(self, times: usize) -> CycleN<'a, T> {
+        CycleN::new(self, times)
     }
 
-    /// Return mutable reference to the start pointer
+    /// Return an iterator that yields elements alternately from `self` and
+    /// `other`, starting with `self`.
     ///
-    /// Unsafe because it is easy to violate memory safety by setting
-    /// the pointer outside the data's valid range.
-    pub unsafe fn end_mut(&mut self) -> &mut *const T {
-        &mut self.end
+    /// When one runs out, the rest of the other follows. This is
+    /// `interleave`, not `interleave_shortest`, in itertools' terms.
+    pub fn interleave(self, other: Self) -> Interleave<'a, T> {
+        Interleave::new(self, other)
     }
 
-    /// Return the next iterator element, without stepping the iterator.
-    pub fn peek_next(&self) -> Option<<Self as Iterator>::Item> {
-        if self.ptr != self.end {
-            unsafe {
-                Some(&*self.ptr)
+    /// Return an iterator over the indices of the elements that satisfy
+    /// `pred`.
+    ///
+    /// The indices are counted from the start at the time this method is
+    /// called. Each search uses the unrolled `find`.
+    pub fn positions<F>(self, pred: F) -> Positions<'a, T, F>
+        where F: FnMut(&T) -> bool,
+    {
+        Positions::new(self, pred)
+    }
+
+    /// Return an iterator that maps each element with `f`.
+    ///
+    /// Like `Iterator::map`, but `all, any, find, position` and `fold`
+    /// still use this iterator's unrolled loops.
+    pub fn map_raw<B, F>(self, f: F) -> MapRaw<'a, T, F>
+        where F: FnMut(&'a T) -> B,
+    {
+        MapRaw::new(self, f)
+    }
+
+    /// Return an iterator that calls `f` on each element as it is visited.
+    ///
+    /// Like `Iterator::inspect`, but `all, any, find, position` and `fold`
+    /// still use this iterator's unrolled loops.
+    pub fn inspect_raw<F>(self, f: F) -> InspectRaw<'a, T, F>
+        where F: FnMut(&T),
+    {
+        InspectRaw::new(self, f)
+    }
+
+    /// Return an iterator that threads the state `init` through the
+    /// elements, yielding the closure's results until it returns `None`.
+    ///
+    /// Like `Iterator::scan`, but this steps the start pointer directly.
+    pub fn scan_raw<St, B, F>(self, init: St, f: F) -> ScanRaw<'a, T, St, F>
+        where F: FnMut(&mut St, &'a T) -> Option<B>,
+    {
+        ScanRaw::new(self, init, f)
+    }
+
+    /// Return an iterator over the elements in reverse order.
+    ///
+    /// Unlike `Iterator::rev`, the reversed iterator keeps the unrolled
+    /// `all, any, find, position`, which then search back to front.
+    pub fn reversed(self) -> RevSliceIter<'a, T> {
+        RevSliceIter::new(self)
+    }
+
+    /// Return an iterator that remembers the current bounds, so that it can
+    /// report how many elements are consumed from each end.
+    pub fn tracked(self) -> TrackedSliceIter<'a, T> {
+        TrackedSliceIter::new(self)
+    }
+
+    /// Return an iterator that records the current start as its base, so
+    /// that its position can be saved and restored as a byte offset.
+    pub fn offset_cursor(self) -> OffsetCursor<'a, T> {
+        OffsetCursor::new(self)
+    }
+
+    /// Return an iterator over pointers to the elements.
+    pub fn ptrs(self) -> Ptrs<'a, T> {
+        Ptrs::new(self)
+    }
+
+    /// Call `f` on each non-overlapping chunk of length `n`, front to back.
+    ///
+    /// The last chunk is shorter if the length is not divisible by `n`.
+    /// This is internal iteration over the same chunks as the slice method
+    /// `chunks`, which gives the optimizer a single loop to work with.
+    ///
+    /// Panics if `n` is zero.
+    pub fn for_each_chunk<F>(self, n: usize, mut f: F)
+        where F: FnMut(&'a [T]),
+    {
+        assert!(n != 0, "chunk size must be non-zero");
+        let mut ptr = self.ptr;
+        unsafe {
+            while ptrdistance(ptr, self.end) >= n {
+                f(slice::from_raw_parts(ptr, n));
+                ptr = ptr.add(n);
+            }
+            if ptr != self.end {
+                f(slice::from_raw_parts(ptr, ptrdistance(ptr, self.end)));
             }
-        } else {
-            None
         }
     }
 
-    /// Return the equivalent slice
-    pub fn as_slice(&self) -> &'a [T] {
+    /// Call `f` on each overlapping window of length `n`, front to back.
+    ///
+    /// This is internal iteration over the same windows as `windows`. If
+    /// `n` is greater than the length, `f` is not called.
+    ///
+    /// Panics if `n` is zero.
+    pub fn for_each_window<F>(self, n: usize, mut f: F)
+        where F: FnMut(&'a [T]),
+    {
+        assert!(n != 0, "window size must be non-zero");
+        let mut ptr = self.ptr;
         unsafe {
-            slice::from_raw_parts(self.ptr, self.len())
+            while ptrdistance(ptr, self.end) >= n {
+                f(slice::from_raw_parts(ptr, n));
+                ptr = ptr.add(1);
+            }
         }
     }
 
-    /// Return the next iterator element, without checking if the end is reached
-    #[inline]
-    pub unsafe fn next_unchecked(&mut self) -> <Self as Iterator>::Item {
-        &*self.ptr.post_inc()
+    /// Return `true` if `pred` returns `true` for any overlapping window of
+    /// length `n`, checking front to back and stopping at the first match.
+    ///
+    /// If `n` is greater than the length, there are no windows and the
+    /// result is `false`.
+    ///
+    /// Panics if `n` is zero.
+    pub fn any_window<F>(&self, n: usize, mut pred: F) -> bool
+        where F: FnMut(&'a [T]) -> bool,
+    {
+        assert!(n != 0, "window size must be non-zero");
+        let len = self.len();
+        if n > len {
+            return false;
+        }
+        // Walk the window starts with the unrolled fold_while, but build
+        // each window from `self.ptr`: the `&T` it yields is only valid for
+        // one element, not for the `n` elements of a window.
+        let base = self.ptr;
+        let mut i = 0;
+        let mut starts = self.take_raw(len - n + 1);
+        starts.fold_while(false, move |_, _| unsafe {
+            let window = slice::from_raw_parts(base.add(i), n);
+            i += 1;
+            if pred(window) {
+                FoldWhile::Done(true)
+            } else {
+                FoldWhile::Continue(false)
+            }
+        })
     }
 
-    /// Return a reference to the element at `i`.
-    pub unsafe fn get_unchecked(&self, i: usize) -> &T {
-        &*self.ptr.offset(i as isize)
+    /// Return an iterator over non-overlapping chunks of length `n`, each
+    /// as its own `SliceIter`.
+    ///
+    /// The last chunk is shorter if the length is not divisible by `n`.
+    ///
+    /// Panics if `n` is zero.
+    pub fn chunk_iters(self, n: usize) -> ChunkIters<'a, T> {
+        ChunkIters::new(self, n)
+    }
+
+    /// Return an iterator over non-overlapping chunks of length `n`,
+    /// starting from the back.
+    ///
+    /// The last chunk yielded, which is at the front of the range, is
+    /// shorter if the length is not divisible by `n`.
+    ///
+    /// Panics if `n` is zero.
+    pub fn rchunks(self, n: usize) -> RChunks<'a, T> {
+        RChunks::new(self, n)
+    }
+
+    /// Return an iterator over non-overlapping chunks of exactly length
+    /// `n`, starting from the back.
+    ///
+    /// If the length is not divisible by `n`, the elements left over at
+    /// the front are not yielded; they are available from `remainder`.
+    ///
+    /// Panics if `n` is zero.
+    pub fn rchunks_exact(self, n: usize) -> RChunksExact<'a, T> {
+        RChunksExact::new(self, n)
+    }
+
+    /// Return an iterator over all overlapping windows of length `n`.
+    ///
+    /// The windows are yielded front to back, stepping one element at a
+    /// time, or back to front with `next_back`. If `n` is larger than the
+    /// length, no windows are produced.
+    ///
+    /// Panics if `n` is zero.
+    pub fn windows(self, n: usize) -> Windows<'a, T> {
+        Windows::new(self, n)
+    }
+
+    /// Return an iterator over all overlapping windows of length `N`, as
+    /// array references.
+    ///
+    /// If `N` is larger than the length, no windows are produced. `N` must
+    /// be non-zero, which is checked at compile time.
+    pub fn array_windows<const N: usize>(self) -> ArrayWindows<'a, T, N> {
+        ArrayWindows::new(self)
+    }
+
+    /// Return an iterator over all overlapping windows of length `N`, as
+    /// array references.
+    ///
+    /// This is `array_windows` under the name that mirrors `windows(n)`.
+    pub fn windows_n<const N: usize>(self) -> WindowsN<'a, T, N> {
+        self.array_windows()
+    }
+}
+
+impl<'a, T: PartialEq> SliceIter<'a, T> {
+    /// Return `true` if the remaining elements start with `needle`.
+    ///
+    /// An empty `needle` always matches.
+    pub fn starts_with(&self, needle: &[T]) -> bool {
+        self.as_slice().starts_with(needle)
     }
+
+    /// Return `true` if the remaining elements end with `needle`.
+    ///
+    /// An empty `needle` always matches.
+    pub fn ends_with(&self, needle: &[T]) -> bool {
+        self.as_slice().ends_with(needle)
+    }
+
+    /// Return `true` if all remaining elements are equal to `x`.
+    ///
+    /// This is `all` with the comparison built in, stopping at the first
+    /// element that is not equal.
+    pub fn all_eq(&mut self, x: &T) -> bool {
+        self.all(move |elt| elt == x)
+    }
+
+    /// Return `true` if any remaining element is equal to `x`.
+    ///
+    /// This is `any` with the comparison built in, stopping at the first
+    /// element that is equal.
+    pub fn any_eq(&mut self, x: &T) -> bool {
+        self.any(move |elt| elt == x)
+    }
+
+    /// Return the index of the first occurrence of `needle` in the
+    /// remaining elements, or `None` if there is none.
+    ///
+    /// An empty `needle` is found at index 0. The search is naive: it
+    /// compares `needle` against each window in turn, so it takes time
+    /// proportional to the product of the lengths in the worst case.
+    pub fn find_subslice(&self, needle: &[T]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        self.windows(needle.len()).position(|window| window == needle)
+    }
+}
+
+/// Check the constructors' pointers, if the `paranoid` feature is enabled.
+#[cfg(feature = "paranoid")]
+#[inline(always)]
+pub(crate) fn check_pointers<T>(start: *const T, end: *const T) {
+    assert!(!start.is_null() && !end.is_null(), "null pointer");
+    assert!(start <= end, "start pointer is after the end pointer");
+}
+
+#[cfg(not(feature = "paranoid"))]
+#[inline(always)]
+pub(crate) fn check_pointers<T>(_start: *const T, _end: *const T) { }
+
+/// Check that `p` is aligned for `T`, in debug builds.
+#[inline(always)]
+pub(crate) fn check_aligned<T>(p: *const T) {
+    debug_assert!((p as usize) % align_of::<T>() == 0,
+                  "pointer is misaligned for the element type");
+}
+
+/// Check that `start` is not after `end` before computing a length, in
+/// debug builds or with the `paranoid` feature.
+#[inline(always)]
+pub(crate) fn check_order<T>(start: *const T, end: *const T) {
+    if cfg!(any(debug_assertions, feature = "paranoid")) {
+        assert!(start <= end, "start pointer is after the end pointer");
+    }
+}
+
+#[cfg(all(feature = "prefetch", target_arch = "x86_64"))]
+#[inline(always)]
+unsafe fn prefetch_read(p: *const i8) {
+    use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+    _mm_prefetch::<_MM_HINT_T0>(p);
+}
+
+#[cfg(all(feature = "prefetch", target_arch = "x86"))]
+#[inline(always)]
+unsafe fn prefetch_read(p: *const i8) {
+    use std::arch::x86::{_mm_prefetch, _MM_HINT_T0};
+    _mm_prefetch::<_MM_HINT_T0>(p);
 }
 
+#[cfg(all(feature = "prefetch", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+#[inline(always)]
+unsafe fn prefetch_read(_p: *const i8) { }
+
 impl<'a, T> Iterator for SliceIter<'a, T> {
     type Item = &'a T;
     #[inline]
@@ -145,10 +1951,69 @@ impl<'a, T> Iterator for SliceIter<'a, T> {
         self.len()
     }
 
-    fn last(mut self) -> Option<Self::Item> {
-        self.next_back()
+    fn last(self) -> Option<Self::Item> {
+        if self.ptr != self.end {
+            unsafe {
+                Some(&*self.end.sub(1))
+            }
+        } else {
+            None
+        }
+    }
+
+    fn fold<Acc, G>(mut self, init: Acc, mut g: G) -> Acc
+        where G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut accum = init;
+        unsafe {
+            while ptrdistance(self.ptr, self.end) >= 4 {
+                self.hint_nonempty();
+                accum = g(accum, &*self.ptr.post_inc());
+                accum = g(accum, &*self.ptr.post_inc());
+                accum = g(accum, &*self.ptr.post_inc());
+                accum = g(accum, &*self.ptr.post_inc());
+            }
+            while self.ptr != self.end {
+                accum = g(accum, &*self.ptr.post_inc());
+            }
+        }
+        accum
+    }
+
+    fn reduce<F>(mut self, f: F) -> Option<Self::Item>
+        where F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        let first = self.next()?;
+        Some(self.fold(first, f))
+    }
+
+    // min_by and max_by always visit every element, so they use `reduce`,
+    // which runs the unrolled `fold`; `fold_while` would only add an exit
+    // check that can never fire.
+    fn min_by<F>(self, mut compare: F) -> Option<Self::Item>
+        where F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        self.reduce(move |best, elt| {
+            match compare(&best, &elt) {
+                Ordering::Greater => elt,
+                _ => best,
+            }
+        })
+    }
+
+    fn max_by<F>(self, mut compare: F) -> Option<Self::Item>
+        where F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        self.reduce(move |best, elt| {
+            match compare(&best, &elt) {
+                Ordering::Greater => best,
+                _ => elt,
+            }
+        })
     }
 
+    /// On `false`, the iterator is left just past the first element that
+    /// failed the predicate, also inside the unrolled loop.
     fn all<F>(&mut self, mut predicate: F) -> bool
         where F: FnMut(Self::Item) -> bool,
     {
@@ -161,12 +2026,16 @@ impl<'a, T> Iterator for SliceIter<'a, T> {
         })
     }
 
+    /// On `true`, the iterator is left just past the first match, also
+    /// inside the unrolled loop.
     fn any<F>(&mut self, mut predicate: F) -> bool
         where F: FnMut(Self::Item) -> bool,
     {
         !self.all(move |x| !predicate(x))
     }
 
+    /// On a match, the iterator is left just past it, also inside the
+    /// unrolled loop, so iteration can resume there.
     fn find<F>(&mut self, mut predicate: F) -> Option<Self::Item>
         where F: FnMut(&Self::Item) -> bool,
     {
@@ -179,20 +2048,21 @@ impl<'a, T> Iterator for SliceIter<'a, T> {
         })
     }
 
+    /// On a match, the iterator is left just past it, also inside the
+    /// unrolled loop, so iteration can resume there.
     fn position<F>(&mut self, mut predicate: F) -> Option<usize>
         where F: FnMut(Self::Item) -> bool,
     {
-        let mut index = 0;
-        self.fold_while(None, move |_, elt| {
+        self.enumerate_fold_while(None, move |_, index, elt| {
             if predicate(elt) {
                 FoldWhile::Done(Some(index))
             } else {
-                index += 1;
                 FoldWhile::Continue(None)
             }
         })
     }
 
+    /// On a match, the back of the iterator is left just before it.
     fn rposition<F>(&mut self, mut predicate: F) -> Option<usize>
         where F: FnMut(Self::Item) -> bool,
     {
@@ -219,10 +2089,34 @@ impl<'a, T> DoubleEndedIterator for SliceIter<'a, T> {
             None
         }
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.nth_back_checked(n)
+    }
+
+    fn rfold<Acc, G>(mut self, init: Acc, mut g: G) -> Acc
+        where G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut accum = init;
+        unsafe {
+            while ptrdistance(self.ptr, self.end) >= 4 {
+                self.hint_nonempty();
+                accum = g(accum, &*self.end.pre_dec());
+                accum = g(accum, &*self.end.pre_dec());
+                accum = g(accum, &*self.end.pre_dec());
+                accum = g(accum, &*self.end.pre_dec());
+            }
+            while self.ptr != self.end {
+                accum = g(accum, &*self.end.pre_dec());
+            }
+        }
+        accum
+    }
 }
 
 impl<'a, T> ExactSizeIterator for SliceIter<'a, T> {
     fn len(&self) -> usize {
+        check_order(self.ptr, self.end);
         ptrdistance(self.ptr, self.end)
     }
 }
@@ -231,12 +2125,42 @@ impl<'a, T> From<&'a [T]> for SliceIter<'a, T> {
     fn from(slice: &'a [T]) -> Self {
         unsafe {
             let ptr = slice.as_ptr();
-            let end = ptr.offset(slice.len() as isize);
+            let end = ptr.add(slice.len());
             SliceIter::new(ptr, end)
         }
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<'a, T> From<&'a Vec<T>> for SliceIter<'a, T> {
+    /// Create a `SliceIter` over the vector's elements.
+    ///
+    /// ```
+    /// use rawslice::SliceIter;
+    ///
+    /// let v = vec![1, 2, 3];
+    /// assert_eq!(SliceIter::from(&v).as_slice(), &[1, 2, 3]);
+    /// ```
+    fn from(v: &'a Vec<T>) -> Self {
+        SliceIter::from(&v[..])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> From<&'a Box<[T]>> for SliceIter<'a, T> {
+    /// Create a `SliceIter` over the boxed slice's elements.
+    ///
+    /// ```
+    /// use rawslice::SliceIter;
+    ///
+    /// let b: Box<[i32]> = vec![1, 2, 3].into_boxed_slice();
+    /// assert_eq!(SliceIter::from(&b).as_slice(), &[1, 2, 3]);
+    /// ```
+    fn from(b: &'a Box<[T]>) -> Self {
+        SliceIter::from(&b[..])
+    }
+}
+
 impl<'a, T> From<CoreSliceIter<'a, T>> for SliceIter<'a, T> {
     fn from(slice: CoreSliceIter<'a, T>) -> Self {
         SliceIter::from(slice.as_slice())
@@ -247,45 +2171,91 @@ impl<'a, T> Default for SliceIter<'a, T> {
     /// Create an empty `SliceIter`.
     fn default() -> Self {
         unsafe {
-            SliceIter::new(0x1 as *const T, 0x1 as *const T)
+            let ptr = NonNull::dangling().as_ptr();
+            SliceIter::new(ptr, ptr)
         }
     }
 }
 
+impl<'a, T> AsRef<[T]> for SliceIter<'a, T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<'a, T> Borrow<[T]> for SliceIter<'a, T> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<'a, T: PartialEq> PartialEq<[T]> for SliceIter<'a, T> {
+    /// Compare the remaining elements to the slice.
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<'a, 'b, T: PartialEq> PartialEq<&'b [T]> for SliceIter<'a, T> {
+    /// Compare the remaining elements to the slice.
+    fn eq(&self, other: &&'b [T]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<'a, T: PartialEq> PartialEq<SliceIter<'a, T>> for [T] {
+    /// Compare the slice to the iterator's remaining elements.
+    fn eq(&self, other: &SliceIter<'a, T>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<'a, T: PartialEq> PartialEq<SliceIter<'a, T>> for &[T] {
+    /// Compare the slice to the iterator's remaining elements.
+    fn eq(&self, other: &SliceIter<'a, T>) -> bool {
+        *self == other.as_slice()
+    }
+}
+
 impl<'a, T> Index<usize> for SliceIter<'a, T> {
     type Output = T;
     fn index(&self, i: usize) -> &T {
         assert!(i < self.len());
         unsafe {
-            &*self.ptr.offset(i as isize)
+            &*self.ptr.add(i)
+        }
+    }
+}
+
+
+macro_rules! index_range {
+    ($($range:ty)*) => {
+        $(
+        impl<'a, T> Index<$range> for SliceIter<'a, T> {
+            type Output = [T];
+            fn index(&self, r: $range) -> &[T] {
+                &self.as_slice()[r]
+            }
         }
+        )*
     }
 }
 
+index_range!(Range<usize> RangeFrom<usize> RangeTo<usize> RangeFull
+             RangeInclusive<usize> RangeToInclusive<usize>);
 
 
 // Fold while implements unrolled searching
 
-#[derive(Copy, Clone, Debug)]
 /// An enum used for controlling the execution of `.fold_while()`.
-enum FoldWhile<T> {
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FoldWhile<T> {
     /// Continue folding with this value
     Continue(T),
     /// Fold is complete and will return this value
     Done(T),
 }
 
-trait FoldWhileExt : Iterator {
-    // Note: For composability (if used with adaptors, return type
-    // should be FoldWhile<Acc> then instead.)
-    fn fold_while<Acc, G>(&mut self, init: Acc, g: G) -> Acc
-        where Self: Sized,
-              G: FnMut(Acc, Self::Item) -> FoldWhile<Acc>;
-    fn rfold_while<Acc, G>(&mut self, accum: Acc, g: G) -> Acc
-        where Self: Sized,
-              G: FnMut(Acc, Self::Item) -> FoldWhile<Acc>;
-}
-
 macro_rules! fold_while {
     ($e:expr) => {
         match $e {
@@ -295,15 +2265,38 @@ macro_rules! fold_while {
     }
 }
 
-impl<'a, T> FoldWhileExt for SliceIter<'a, T> {
-    fn fold_while<Acc, G>(&mut self, init: Acc, mut g: G) -> Acc
-        where Self: Sized,
-              G: FnMut(Acc, Self::Item) -> FoldWhile<Acc>
+impl<'a, T> SliceIter<'a, T> {
+    /// Fold the elements front to back, stopping early when `g` returns
+    /// `FoldWhile::Done`.
+    ///
+    /// The loop is unrolled. On an early exit, the elements up to and
+    /// including the one that returned `Done` are consumed, and the value
+    /// it was returned with is the result.
+    ///
+    /// ```
+    /// use rawslice::{SliceIter, FoldWhile};
+    ///
+    /// // sum the elements, stopping before the sum exceeds 10
+    /// let data = [1, 2, 3, 4, 5, 6];
+    /// let mut iter = SliceIter::from(&data[..]);
+    /// let sum = iter.fold_while(0, |acc, &x| {
+    ///     if acc + x > 10 {
+    ///         FoldWhile::Done(acc)
+    ///     } else {
+    ///         FoldWhile::Continue(acc + x)
+    ///     }
+    /// });
+    /// assert_eq!(sum, 10);
+    /// assert_eq!(iter.as_slice(), &[6]);
+    /// ```
+    pub fn fold_while<Acc, G>(&mut self, init: Acc, mut g: G) -> Acc
+        where G: FnMut(Acc, &'a T) -> FoldWhile<Acc>
     {
 
         let mut accum = init;
         unsafe {
             while ptrdistance(self.ptr, self.end) >= 4 {
+                self.hint_nonempty();
                 accum = fold_while!(g(accum, &*self.ptr.post_inc()));
                 accum = fold_while!(g(accum, &*self.ptr.post_inc()));
                 accum = fold_while!(g(accum, &*self.ptr.post_inc()));
@@ -316,13 +2309,49 @@ impl<'a, T> FoldWhileExt for SliceIter<'a, T> {
         accum
     }
 
-    fn rfold_while<Acc, G>(&mut self, mut accum: Acc, mut g: G) -> Acc
-        where Self: Sized,
-              G: FnMut(Acc, Self::Item) -> FoldWhile<Acc>
+    /// Fold the elements front to back together with their indices,
+    /// stopping early when `g` returns `FoldWhile::Done`.
+    ///
+    /// Like `fold_while`, but `g` also receives the index of the element,
+    /// counted from the start when this method is called. The index is
+    /// computed from the pointer distance.
+    pub fn enumerate_fold_while<Acc, G>(&mut self, init: Acc, mut g: G) -> Acc
+        where G: FnMut(Acc, usize, &'a T) -> FoldWhile<Acc>
+    {
+        let start = self.ptr;
+        self.fold_while(init, move |accum, elt| {
+            g(accum, ptrdistance(start, elt), elt)
+        })
+    }
+
+    /// Fold the elements back to front, stopping early when `g` returns
+    /// `FoldWhile::Done`.
+    ///
+    /// Like `fold_while`, but from the back.
+    ///
+    /// ```
+    /// use rawslice::{SliceIter, FoldWhile};
+    ///
+    /// // sum the elements from the back, stopping at the first zero
+    /// let data = [1, 2, 0, 4, 5];
+    /// let mut iter = SliceIter::from(&data[..]);
+    /// let sum = iter.rfold_while(0, |acc, &x| {
+    ///     if x == 0 {
+    ///         FoldWhile::Done(acc)
+    ///     } else {
+    ///         FoldWhile::Continue(acc + x)
+    ///     }
+    /// });
+    /// assert_eq!(sum, 9);
+    /// assert_eq!(iter.as_slice(), &[1, 2]);
+    /// ```
+    pub fn rfold_while<Acc, G>(&mut self, mut accum: Acc, mut g: G) -> Acc
+        where G: FnMut(Acc, &'a T) -> FoldWhile<Acc>
     {
         // manual unrolling is needed when there are conditional exits from the loop's body.
         unsafe {
             while ptrdistance(self.ptr, self.end) >= 4 {
+                self.hint_nonempty();
                 accum = fold_while!(g(accum, &*self.end.pre_dec()));
                 accum = fold_while!(g(accum, &*self.end.pre_dec()));
                 accum = fold_while!(g(accum, &*self.end.pre_dec()));
@@ -334,4 +2363,31 @@ impl<'a, T> FoldWhileExt for SliceIter<'a, T> {
         }
         accum
     }
+
+    /// Fold the elements front to back like `fold_while`, and also return
+    /// whether `g` stopped the fold early.
+    ///
+    /// The flag is `true` if `g` returned `FoldWhile::Done`, and `false` if
+    /// all the elements were folded.
+    ///
+    /// ```
+    /// use rawslice::{SliceIter, FoldWhile};
+    ///
+    /// let data = [1, 2, 3, 4, 5];
+    /// let fold = |acc: i32, &x: &i32| {
+    ///     if x == 3 { FoldWhile::Done(acc) } else { FoldWhile::Continue(acc + x) }
+    /// };
+    /// assert_eq!(SliceIter::from(&data[..]).fold_reporting(0, fold), (3, true));
+    /// assert_eq!(SliceIter::from(&data[3..]).fold_reporting(0, fold), (9, false));
+    /// ```
+    pub fn fold_reporting<Acc, G>(&mut self, init: Acc, mut g: G) -> (Acc, bool)
+        where G: FnMut(Acc, &'a T) -> FoldWhile<Acc>
+    {
+        self.fold_while((init, false), move |(accum, _), elt| {
+            match g(accum, elt) {
+                FoldWhile::Continue(accum) => FoldWhile::Continue((accum, false)),
+                FoldWhile::Done(accum) => FoldWhile::Done((accum, true)),
+            }
+        })
+    }
 }
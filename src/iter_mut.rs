@@ -0,0 +1,474 @@
+//! Mutable slice iterators
+
+use std::mem::size_of;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut, Range};
+use std::ptr;
+use std::ptr::NonNull;
+use std::slice;
+
+use rawpointer::PointerExt;
+use rawpointer::ptrdistance;
+
+use chunks::{ChunksMut, ChunksExactMut, PairsMut};
+use iter::{check_pointers, check_order, check_aligned};
+
+/// Mutable slice (contiguous data) iterator.
+///
+/// Iterator element type is `&mut T`
+///
+/// This is the mutable counterpart of `SliceIter`, with the same raw pointer
+/// constructor and accessors.
+///
+/// Zero-sized element types are not supported.
+#[derive(Debug)]
+pub struct SliceIterMut<'a, T: 'a> {
+    ptr: *mut T,
+    end: *mut T,
+    ty: PhantomData<&'a mut T>,
+}
+
+unsafe impl<'a, T: Send> Send for SliceIterMut<'a, T> { }
+unsafe impl<'a, T: Sync> Sync for SliceIterMut<'a, T> { }
+
+impl<'a, T> SliceIterMut<'a, T> {
+    /// Create a new mutable slice iterator
+    ///
+    /// See also ``SliceIterMut::from, SliceIterMut::default``.
+    ///
+    /// Panics if `T` is a zero-sized type. That case is not supported.
+    ///
+    /// With the `paranoid` feature, also panics if either pointer is null
+    /// or if `start` is after `end`. In debug builds, also panics if either
+    /// pointer is misaligned for `T`.
+    #[inline]
+    pub unsafe fn new(start: *mut T, end: *mut T) -> Self {
+        assert!(size_of::<T>() != 0);
+        check_pointers(start, end);
+        check_aligned(start);
+        check_aligned(end);
+        SliceIterMut {
+            ptr: start,
+            end,
+            ty: PhantomData,
+        }
+    }
+
+    /// Create a new mutable slice iterator from a start pointer and a length
+    ///
+    /// Unsafe because the pointer must be valid for reading and writing
+    /// `len` elements, without other access, for the lifetime `'a`.
+    ///
+    /// Panics if `T` is a zero-sized type. That case is not supported.
+    pub unsafe fn from_raw_parts(ptr: *mut T, len: usize) -> Self {
+        SliceIterMut::new(ptr, ptr.add(len))
+    }
+
+    /// Return the start pointer and the number of remaining elements
+    ///
+    /// This is the inverse of `from_raw_parts`.
+    pub fn into_raw_parts(self) -> (*mut T, usize) {
+        (self.ptr, self.len())
+    }
+
+    /// Return the start pointer
+    pub fn start(&self) -> *mut T {
+        self.ptr
+    }
+
+    /// Return the end pointer
+    pub fn end(&self) -> *mut T {
+        self.end
+    }
+
+    /// Return mutable reference to the start pointer
+    ///
+    /// Unsafe because it is easy to violate memory safety by setting
+    /// the pointer outside the data's valid range.
+    pub unsafe fn start_mut(&mut self) -> &mut *mut T {
+        &mut self.ptr
+    }
+
+    /// Return mutable reference to the end pointer
+    ///
+    /// Unsafe because it is easy to violate memory safety by setting
+    /// the pointer outside the data's valid range.
+    pub unsafe fn end_mut(&mut self) -> &mut *mut T {
+        &mut self.end
+    }
+
+    /// Return the start pointer, for writing
+    ///
+    /// Writing outside the remaining range through this pointer is
+    /// undefined behavior.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
+    /// Return the remaining range as a pair of pointers, for writing
+    ///
+    /// Writing outside the range is undefined behavior.
+    pub fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
+        self.ptr..self.end
+    }
+
+    /// Set the start pointer
+    ///
+    /// Unsafe because the caller must keep the start pointer within the
+    /// data's valid range, in the same allocation as the end pointer, and
+    /// not past the end pointer.
+    pub unsafe fn set_start(&mut self, p: *mut T) {
+        self.ptr = p;
+    }
+
+    /// Set the end pointer
+    ///
+    /// Unsafe because the caller must keep the end pointer within the
+    /// data's valid range, in the same allocation as the start pointer, and
+    /// not before the start pointer.
+    pub unsafe fn set_end(&mut self, p: *mut T) {
+        self.end = p;
+    }
+
+    /// Return the equivalent slice
+    pub fn as_slice(&self) -> &[T] {
+        unsafe {
+            slice::from_raw_parts(self.ptr, self.len())
+        }
+    }
+
+    /// Return the equivalent mutable slice, borrowing the iterator
+    ///
+    /// Changes through the slice are seen by the elements the iterator
+    /// yields later.
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        unsafe {
+            slice::from_raw_parts_mut(self.ptr, self.len())
+        }
+    }
+
+    /// Return the equivalent mutable slice, consuming the iterator
+    pub fn into_slice(self) -> &'a mut [T] {
+        unsafe {
+            slice::from_raw_parts_mut(self.ptr, self.len())
+        }
+    }
+
+    /// Return the next iterator element, without checking if the end is reached
+    #[inline]
+    pub unsafe fn next_unchecked(&mut self) -> <Self as Iterator>::Item {
+        &mut *self.ptr.post_inc()
+    }
+
+    /// Split the iterator in two at index `i`; the first half has the
+    /// elements before `i` and the second half the rest.
+    ///
+    /// The halves do not overlap.
+    ///
+    /// Panics if `i` is greater than the length.
+    pub fn split_at_mut(self, i: usize) -> (Self, Self) {
+        assert!(i <= self.len());
+        unsafe {
+            let mid = self.ptr.add(i);
+            (SliceIterMut::new(self.ptr, mid), SliceIterMut::new(mid, self.end))
+        }
+    }
+
+    /// Assign elements from `src` to the remaining elements, front to back,
+    /// until either runs out, and return how many were written.
+    ///
+    /// The written elements are consumed from the iterator, and the old
+    /// values are dropped. No element is taken from `src` when the iterator
+    /// is already at its end.
+    pub fn write_all<I>(&mut self, src: I) -> usize
+        where I: IntoIterator<Item = T>,
+    {
+        let mut src = src.into_iter();
+        let mut count = 0;
+        while self.ptr != self.end {
+            match src.next() {
+                None => break,
+                Some(elt) => unsafe {
+                    *self.next_unchecked() = elt;
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Copy the elements in the index range `src` of the remaining range to
+    /// the position starting at index `dest`, like `[T]::copy_within`.
+    ///
+    /// The source and destination may overlap.
+    ///
+    /// Panics if either range extends past the end, or if `src` is
+    /// decreasing.
+    pub fn copy_within(&mut self, src: Range<usize>, dest: usize)
+        where T: Copy,
+    {
+        let len = self.len();
+        assert!(src.start <= src.end && src.end <= len, "source range out of bounds");
+        let count = src.end - src.start;
+        assert!(dest <= len - count, "destination out of bounds");
+        unsafe {
+            ptr::copy(self.ptr.add(src.start), self.ptr.add(dest), count);
+        }
+    }
+
+    /// Swap the elements at indices `a` and `b` of the remaining range.
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let len = self.len();
+        assert!(a < len && b < len);
+        unsafe {
+            self.swap_unchecked(a, b);
+        }
+    }
+
+    /// Swap the elements at indices `a` and `b` of the remaining range,
+    /// without bounds checking.
+    ///
+    /// Unsafe because both indices must be less than the length.
+    pub unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        debug_assert!(a < self.len() && b < self.len());
+        ptr::swap(self.ptr.add(a), self.ptr.add(b));
+    }
+
+    /// Assign clones of `value` to all the remaining elements, like
+    /// `[T]::fill`.
+    ///
+    /// The old values are dropped; `value` itself is moved into the last
+    /// element.
+    pub fn fill(mut self, value: T)
+        where T: Clone,
+    {
+        if let Some(last) = self.next_back() {
+            for elt in self {
+                *elt = value.clone();
+            }
+            *last = value;
+        }
+    }
+
+    /// Assign the values returned by `f` to all the remaining elements,
+    /// front to back, like `[T]::fill_with`.
+    ///
+    /// The old values are dropped.
+    pub fn fill_with<F>(self, mut f: F)
+        where F: FnMut() -> T,
+    {
+        for elt in self {
+            *elt = f();
+        }
+    }
+
+    /// Move the elements for which `keep` returns `true` to the front of
+    /// the remaining range, keeping their order, and return how many there
+    /// are.
+    ///
+    /// This is the algorithm of `Vec::retain`, but the rejected elements
+    /// are not dropped; they are moved, in unspecified order, to the back of
+    /// the range, where the caller can truncate them off.
+    pub fn compact<F>(self, mut keep: F) -> usize
+        where F: FnMut(&T) -> bool,
+    {
+        let start = self.ptr;
+        let mut write = start;
+        for elt in self {
+            if keep(elt) {
+                unsafe {
+                    let elt = elt as *mut T;
+                    if elt != write {
+                        ptr::swap_nonoverlapping(elt, write, 1);
+                    }
+                    write = write.add(1);
+                }
+            }
+        }
+        ptrdistance(start, write)
+    }
+
+    /// Reverse the order of the remaining elements in place.
+    ///
+    /// The start and end pointers walk inward, swapping as they go, like
+    /// `[T]::reverse`.
+    pub fn reverse(self) {
+        unsafe {
+            reverse_range(self.ptr, self.end);
+        }
+    }
+
+    /// Rotate the remaining elements in place so that the element at index
+    /// `mid` becomes the first, like `[T]::rotate_left`.
+    ///
+    /// This uses three reversals of the pointer ranges.
+    ///
+    /// Panics if `mid` is greater than the length.
+    pub fn rotate_left(self, mid: usize) {
+        assert!(mid <= self.len());
+        unsafe {
+            let mid = self.ptr.add(mid);
+            reverse_range(self.ptr, mid);
+            reverse_range(mid, self.end);
+            reverse_range(self.ptr, self.end);
+        }
+    }
+
+    /// Rotate the remaining elements in place so that the last `k` elements
+    /// come first, like `[T]::rotate_right`.
+    ///
+    /// Panics if `k` is greater than the length.
+    pub fn rotate_right(self, k: usize) {
+        let len = self.len();
+        assert!(k <= len);
+        self.rotate_left(len - k);
+    }
+
+    /// Return an iterator over non-overlapping mutable chunks of length `n`.
+    ///
+    /// The last chunk is shorter if the length is not divisible by `n`.
+    ///
+    /// Panics if `n` is zero.
+    pub fn chunks_mut(self, n: usize) -> ChunksMut<'a, T> {
+        ChunksMut::new(self, n)
+    }
+
+    /// Return an iterator over non-overlapping mutable chunks of exactly
+    /// `N` elements, as array references.
+    ///
+    /// The last elements that don't fill a chunk are available from
+    /// `ChunksExactMut::into_remainder`. `N` must be non-zero, which is
+    /// checked at compile time.
+    pub fn chunks_exact_mut<const N: usize>(self) -> ChunksExactMut<'a, T, N> {
+        ChunksExactMut::new(self)
+    }
+
+    /// Return an iterator over non-overlapping pairs of adjacent elements,
+    /// stepping by two.
+    ///
+    /// There is no mutable `windows`, since overlapping windows would alias;
+    /// this is the disjoint alternative for pairwise updates. A last element
+    /// without a pair is available from `PairsMut::into_remainder`.
+    pub fn pairs_mut(self) -> PairsMut<'a, T> {
+        PairsMut::new(self)
+    }
+}
+
+/// Reverse the elements in `front..back` in place.
+///
+/// Unsafe because the range must be valid for reading and writing.
+unsafe fn reverse_range<T>(mut front: *mut T, mut back: *mut T) {
+    while ptrdistance(front, back) >= 2 {
+        back = back.sub(1);
+        ptr::swap_nonoverlapping(front, back, 1);
+        front = front.add(1);
+    }
+}
+
+impl<'a, T> Iterator for SliceIterMut<'a, T> {
+    type Item = &'a mut T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ptr != self.end {
+            unsafe {
+                Some(&mut *self.ptr.post_inc())
+            }
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for SliceIterMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.ptr != self.end {
+            unsafe {
+                Some(&mut *self.end.pre_dec())
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for SliceIterMut<'a, T> {
+    fn len(&self) -> usize {
+        check_order(self.ptr, self.end);
+        ptrdistance(self.ptr, self.end)
+    }
+}
+
+impl<'a, T> From<&'a mut [T]> for SliceIterMut<'a, T> {
+    fn from(slice: &'a mut [T]) -> Self {
+        unsafe {
+            let ptr = slice.as_mut_ptr();
+            let end = ptr.add(slice.len());
+            SliceIterMut::new(ptr, end)
+        }
+    }
+}
+
+impl<'a, T> From<slice::IterMut<'a, T>> for SliceIterMut<'a, T> {
+    /// Convert a libcore mutable slice iterator over the same remaining
+    /// elements.
+    ///
+    /// ```
+    /// use rawslice::SliceIterMut;
+    ///
+    /// let mut data = [1, 2, 3, 4];
+    /// let mut core_iter = data.iter_mut();
+    /// core_iter.next();
+    /// for x in SliceIterMut::from(core_iter) {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(data, [1, 20, 30, 40]);
+    /// ```
+    fn from(iter: slice::IterMut<'a, T>) -> Self {
+        SliceIterMut::from(iter.into_slice())
+    }
+}
+
+impl<'a, T> Default for SliceIterMut<'a, T> {
+    /// Create an empty `SliceIterMut`.
+    fn default() -> Self {
+        unsafe {
+            let ptr = NonNull::dangling().as_ptr();
+            SliceIterMut::new(ptr, ptr)
+        }
+    }
+}
+
+impl<'a, T> Index<usize> for SliceIterMut<'a, T> {
+    type Output = T;
+    fn index(&self, i: usize) -> &T {
+        assert!(i < self.len());
+        unsafe {
+            &*self.ptr.add(i)
+        }
+    }
+}
+
+impl<'a, T> IndexMut<usize> for SliceIterMut<'a, T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        assert!(i < self.len());
+        unsafe {
+            &mut *self.ptr.add(i)
+        }
+    }
+}
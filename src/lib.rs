@@ -1,9 +1,53 @@
 #![no_std]
+#![allow(clippy::missing_safety_doc)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 extern crate rawpointer;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
 
 extern crate core as std;
 
+mod adaptors;
+mod chunks;
+mod error;
+mod group;
 mod iter;
+mod iter_mut;
+#[cfg(feature = "multilane")]
+mod multilane;
+mod offset_cursor;
+mod raw;
+mod repeat;
+mod rev;
+mod split;
+mod stack;
+mod tracked;
+mod windows;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "serde")]
+mod impl_serde;
 
-pub use iter::{SliceIter};
+pub use iter::{SliceIter, FoldWhile};
+pub use iter_mut::SliceIterMut;
+pub use error::{SliceIterError, IndexError};
+pub use offset_cursor::OffsetCursor;
+pub use chunks::{ChunksMut, ChunksExactMut, RChunks, RChunksExact, ChunkIters, PairsMut};
+pub use adaptors::{StepByRaw, EnumerateRaw, Ptrs, ScanRaw, CycleN, InspectRaw,
+                   MapRaw, Positions, Interleave};
+pub use group::{GroupBy, Dedup, DedupByKey, RunLength};
+pub use raw::RawSlice;
+pub use repeat::Repeat;
+pub use rev::RevSliceIter;
+#[cfg(feature = "multilane")]
+pub use multilane::MultiLaneSum;
+pub use split::{Split, SplitInclusive, SplitTerminator, SplitSkipEmpty, RSplit, RSplitN};
+pub use stack::StackSlice;
+pub use tracked::TrackedSliceIter;
+pub use windows::{Windows, ArrayWindows, WindowsN};
+#[cfg(feature = "rayon")]
+pub use par::{ParSliceIter, ParSliceIterMut};
@@ -0,0 +1,59 @@
+//! Multi-lane summation
+
+/// Number of independent accumulators used by `MultiLaneSum`.
+const LANES: usize = 8;
+
+/// Element types that `SliceIter::multilane_sum` supports.
+///
+/// The sum is kept in eight independent accumulators, one per lane, that
+/// are added together at the end. This is plain scalar code: there are no
+/// SIMD intrinsics, and vectorization is left to the compiler, which the
+/// independent lanes make possible. In `benches/sum.rs` (2^20 elements,
+/// x86-64, default target features) it takes about 171 µs for `i32` and
+/// 170 µs for `f32`, against 238 µs and 844 µs for `sum` with the unrolled
+/// `fold`.
+///
+/// Integers are summed with `+`, like the scalar sum, so with overflow
+/// checks enabled (as in debug mode) overflow panics; otherwise the result
+/// is exactly equal to the scalar sum. Unsigned sums overflow in exactly
+/// the same cases as the scalar sum. Signed sums can differ in whether a
+/// partial sum overflows, since the elements are added in another order.
+///
+/// Floats are summed in a different order than the scalar sum (each lane
+/// sums every eighth element, then the lanes are added together), so the
+/// result may differ from it by rounding. All sums start at `-0.0`, like
+/// the scalar sum, so that summing only negative zeros gives `-0.0`.
+pub trait MultiLaneSum: Copy {
+    #[doc(hidden)]
+    fn multilane_sum(slice: &[Self]) -> Self;
+}
+
+macro_rules! multilane_sum {
+    ($zero:expr, $($t:ty)*) => {
+        $(
+        impl MultiLaneSum for $t {
+            fn multilane_sum(slice: &[Self]) -> Self {
+                let chunks = slice.chunks_exact(LANES);
+                let tail = chunks.remainder();
+
+                // independent accumulators that the compiler can keep in
+                // vector registers
+                let mut lanes: [Self; LANES] = [$zero; LANES];
+                for chunk in chunks {
+                    for (lane, &elt) in lanes.iter_mut().zip(chunk) {
+                        *lane += elt;
+                    }
+                }
+                let mut sum: Self = $zero;
+                for &elt in lanes.iter().chain(tail) {
+                    sum += elt;
+                }
+                sum
+            }
+        }
+        )*
+    }
+}
+
+multilane_sum!(0, i8 i16 i32 i64 isize u8 u16 u32 u64 usize);
+multilane_sum!(-0., f32 f64);
@@ -0,0 +1,120 @@
+//! Slice iterator with a persistent byte offset
+
+use std::mem::size_of;
+
+use rawpointer::ptrdistance;
+
+use error::SliceIterError;
+use iter::SliceIter;
+
+/// Slice iterator that records its base pointer, so that its position can
+/// be saved and restored as a byte offset.
+///
+/// Iterator element type is `&T`
+///
+/// Created with `SliceIter::offset_cursor`.
+///
+/// This is for incremental parsing, for example with a scanner in C that is
+/// called repeatedly, where only an offset is kept between the calls.
+#[derive(Debug)]
+pub struct OffsetCursor<'a, T: 'a> {
+    base: *const T,
+    iter: SliceIter<'a, T>,
+}
+
+unsafe impl<'a, T: Sync> Send for OffsetCursor<'a, T> { }
+unsafe impl<'a, T: Sync> Sync for OffsetCursor<'a, T> { }
+
+impl<'a, T> Copy for OffsetCursor<'a, T> { }
+impl<'a, T> Clone for OffsetCursor<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> OffsetCursor<'a, T> {
+    /// Create a cursor at the start of `iter`, which becomes the base that
+    /// offsets are measured from.
+    pub fn new(iter: SliceIter<'a, T>) -> Self {
+        OffsetCursor {
+            base: iter.start(),
+            iter,
+        }
+    }
+
+    /// Return the number of bytes consumed from the base
+    pub fn consumed(&self) -> usize {
+        ptrdistance(self.base, self.iter.start()) * size_of::<T>()
+    }
+
+    /// Move the position to `offset` bytes from the base.
+    ///
+    /// Any offset between the base and the end, inclusive, is accepted, so
+    /// this can also move back to an earlier position. Return
+    /// `SliceIterError::SizeNotMultiple` if `offset` is not a multiple of
+    /// the element size and `SliceIterError::OutOfBounds` if it is past the
+    /// end; the position is unchanged on error.
+    pub fn resume_at(&mut self, offset: usize) -> Result<(), SliceIterError> {
+        if offset % size_of::<T>() != 0 {
+            return Err(SliceIterError::SizeNotMultiple);
+        }
+        let index = offset / size_of::<T>();
+        if index > ptrdistance(self.base, self.iter.end()) {
+            return Err(SliceIterError::OutOfBounds);
+        }
+        unsafe {
+            self.iter.set_start(self.base.add(index));
+        }
+        Ok(())
+    }
+
+    /// Return the remaining elements as a slice
+    pub fn as_slice(&self) -> &'a [T] {
+        self.iter.as_slice()
+    }
+
+    /// Return the iterator over the remaining elements, without the base
+    pub fn into_inner(self) -> SliceIter<'a, T> {
+        self.iter
+    }
+}
+
+impl<'a, T> From<SliceIter<'a, T>> for OffsetCursor<'a, T> {
+    fn from(iter: SliceIter<'a, T>) -> Self {
+        OffsetCursor::new(iter)
+    }
+}
+
+impl<'a, T> Iterator for OffsetCursor<'a, T> {
+    type Item = &'a T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.iter.len()
+    }
+
+    fn fold<Acc, G>(self, init: Acc, g: G) -> Acc
+        where G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, g)
+    }
+
+    fn find<F>(&mut self, predicate: F) -> Option<Self::Item>
+        where F: FnMut(&Self::Item) -> bool,
+    {
+        self.iter.find(predicate)
+    }
+
+    fn position<F>(&mut self, predicate: F) -> Option<usize>
+        where F: FnMut(Self::Item) -> bool,
+    {
+        self.iter.position(predicate)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for OffsetCursor<'a, T> { }
@@ -0,0 +1,143 @@
+//! Rayon parallel iterator support
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator, IndexedParallelIterator};
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback};
+use rayon::iter::plumbing::UnindexedConsumer;
+
+use iter::SliceIter;
+use iter_mut::SliceIterMut;
+
+/// Parallel iterator over the elements of a `SliceIter`.
+///
+/// Iterator element type is `&T`
+///
+/// Created with `SliceIter::into_par_iter`. The range is handed out to
+/// rayon's workers by splitting the pointer range recursively.
+#[derive(Debug)]
+pub struct ParSliceIter<'a, T: 'a + Sync> {
+    iter: SliceIter<'a, T>,
+}
+
+impl<'a, T: Sync> IntoParallelIterator for SliceIter<'a, T> {
+    type Iter = ParSliceIter<'a, T>;
+    type Item = &'a T;
+    fn into_par_iter(self) -> Self::Iter {
+        ParSliceIter { iter: self }
+    }
+}
+
+impl<'a, T: Sync> ParallelIterator for ParSliceIter<'a, T> {
+    type Item = &'a T;
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+impl<'a, T: Sync> IndexedParallelIterator for ParSliceIter<'a, T> {
+    fn drive<C>(self, consumer: C) -> C::Result
+        where C: Consumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+        where CB: ProducerCallback<Self::Item>
+    {
+        callback.callback(SliceProducer { iter: self.iter })
+    }
+}
+
+struct SliceProducer<'a, T: 'a + Sync> {
+    iter: SliceIter<'a, T>,
+}
+
+impl<'a, T: Sync> Producer for SliceProducer<'a, T> {
+    type Item = &'a T;
+    type IntoIter = SliceIter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (a, b) = self.iter.split_at(index);
+        (SliceProducer { iter: a }, SliceProducer { iter: b })
+    }
+}
+
+/// Parallel iterator over the elements of a `SliceIterMut`.
+///
+/// Iterator element type is `&mut T`
+///
+/// Created with `SliceIterMut::into_par_iter`. The range is handed out to
+/// rayon's workers by splitting it recursively with `split_at_mut`, so no
+/// two workers get overlapping elements.
+#[derive(Debug)]
+pub struct ParSliceIterMut<'a, T: 'a + Send> {
+    iter: SliceIterMut<'a, T>,
+}
+
+impl<'a, T: Send> IntoParallelIterator for SliceIterMut<'a, T> {
+    type Iter = ParSliceIterMut<'a, T>;
+    type Item = &'a mut T;
+    fn into_par_iter(self) -> Self::Iter {
+        ParSliceIterMut { iter: self }
+    }
+}
+
+impl<'a, T: Send> ParallelIterator for ParSliceIterMut<'a, T> {
+    type Item = &'a mut T;
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+impl<'a, T: Send> IndexedParallelIterator for ParSliceIterMut<'a, T> {
+    fn drive<C>(self, consumer: C) -> C::Result
+        where C: Consumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+        where CB: ProducerCallback<Self::Item>
+    {
+        callback.callback(SliceMutProducer { iter: self.iter })
+    }
+}
+
+struct SliceMutProducer<'a, T: 'a + Send> {
+    iter: SliceIterMut<'a, T>,
+}
+
+impl<'a, T: Send> Producer for SliceMutProducer<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = SliceIterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (a, b) = self.iter.split_at_mut(index);
+        (SliceMutProducer { iter: a }, SliceMutProducer { iter: b })
+    }
+}
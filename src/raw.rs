@@ -0,0 +1,124 @@
+//! Raw slice value type
+
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::ops::Range;
+use std::ptr::NonNull;
+use std::slice;
+
+use iter::SliceIter;
+
+/// A borrowed slice stored as a pointer and length.
+///
+/// `RawSlice` is a `Copy` stand-in for `&[T]` that iterates using
+/// `SliceIter`.
+///
+/// Zero-sized element types are not supported.
+#[derive(Debug)]
+pub struct RawSlice<'a, T: 'a> {
+    ptr: NonNull<T>,
+    len: usize,
+    ty: PhantomData<&'a T>,
+}
+
+unsafe impl<'a, T: Sync> Send for RawSlice<'a, T> { }
+unsafe impl<'a, T: Sync> Sync for RawSlice<'a, T> { }
+
+impl<'a, T> Copy for RawSlice<'a, T> { }
+impl<'a, T> Clone for RawSlice<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> RawSlice<'a, T> {
+    /// Return the length
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the length is zero
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the start pointer
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr.as_ptr()
+    }
+
+    /// Return the equivalent slice
+    pub fn as_slice(&self) -> &'a [T] {
+        unsafe {
+            slice::from_raw_parts(self.ptr.as_ptr(), self.len)
+        }
+    }
+
+    /// Return an iterator over the elements
+    pub fn iter(&self) -> SliceIter<'a, T> {
+        SliceIter::from(self.as_slice())
+    }
+
+    /// Return a reference to the element at index `i`, or `None` if it is
+    /// out of bounds.
+    pub fn get(&self, i: usize) -> Option<&'a T> {
+        if i < self.len {
+            unsafe {
+                Some(&*self.ptr.as_ptr().add(i))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Return the first element, or `None` if the slice is empty.
+    pub fn first(&self) -> Option<&'a T> {
+        self.get(0)
+    }
+
+    /// Return the last element, or `None` if the slice is empty.
+    pub fn last(&self) -> Option<&'a T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    /// Split the slice in two at index `i`; the first half has the
+    /// elements before `i` and the second half the rest.
+    ///
+    /// Panics if `i` is greater than the length.
+    pub fn split_at(self, i: usize) -> (Self, Self) {
+        assert!(i <= self.len);
+        unsafe {
+            let mid = NonNull::new_unchecked(self.ptr.as_ptr().add(i));
+            (RawSlice { ptr: self.ptr, len: i, ty: PhantomData },
+             RawSlice { ptr: mid, len: self.len - i, ty: PhantomData })
+        }
+    }
+
+    /// Return the part of the slice in the index range `r`.
+    ///
+    /// Panics if the range is decreasing or extends past the end.
+    pub fn subslice(self, r: Range<usize>) -> Self {
+        assert!(r.start <= r.end);
+        let (_, rest) = self.split_at(r.start);
+        let (mid, _) = rest.split_at(r.end - r.start);
+        mid
+    }
+}
+
+impl<'a, T> From<&'a [T]> for RawSlice<'a, T> {
+    /// Panics if `T` is a zero-sized type. That case is not supported.
+    fn from(slice: &'a [T]) -> Self {
+        assert!(size_of::<T>() != 0);
+        RawSlice {
+            ptr: NonNull::from(slice).cast(),
+            len: slice.len(),
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for RawSlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = SliceIter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        SliceIter::from(self.as_slice())
+    }
+}
@@ -0,0 +1,60 @@
+//! Iterator repeating a single element
+
+/// Iterator that yields the same element reference a fixed number of times.
+///
+/// Iterator element type is `&T`
+///
+/// This broadcasts a single element without filling a buffer with copies.
+#[derive(Debug)]
+pub struct Repeat<'a, T: 'a> {
+    elem: &'a T,
+    n: usize,
+}
+
+impl<'a, T> Copy for Repeat<'a, T> { }
+impl<'a, T> Clone for Repeat<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> Repeat<'a, T> {
+    /// Create an iterator that yields `elem` `n` times.
+    pub fn new(elem: &'a T, n: usize) -> Self {
+        Repeat { elem, n }
+    }
+}
+
+impl<'a, T> Iterator for Repeat<'a, T> {
+    type Item = &'a T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n == 0 {
+            None
+        } else {
+            self.n -= 1;
+            Some(self.elem)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.n, Some(self.n))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.n {
+            self.n -= n + 1;
+            Some(self.elem)
+        } else {
+            self.n = 0;
+            None
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Repeat<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Repeat<'a, T> { }
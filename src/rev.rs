@@ -0,0 +1,114 @@
+//! Reversed slice iterator
+
+use iter::{SliceIter, FoldWhile};
+
+/// Reversed slice iterator.
+///
+/// Iterator element type is `&T`
+///
+/// Created with `SliceIter::reversed`. This yields the elements of the
+/// `SliceIter` back to front, while keeping its unrolled `all, any, find,
+/// position`.
+#[derive(Debug)]
+pub struct RevSliceIter<'a, T: 'a> {
+    iter: SliceIter<'a, T>,
+}
+
+impl<'a, T> Copy for RevSliceIter<'a, T> { }
+impl<'a, T> Clone for RevSliceIter<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> RevSliceIter<'a, T> {
+    pub(crate) fn new(iter: SliceIter<'a, T>) -> Self {
+        RevSliceIter { iter }
+    }
+
+    /// Return the iterator in the original order
+    pub fn reversed(self) -> SliceIter<'a, T> {
+        self.iter
+    }
+}
+
+impl<'a, T> Iterator for RevSliceIter<'a, T> {
+    type Item = &'a T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.iter.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn fold<Acc, G>(self, init: Acc, g: G) -> Acc
+        where G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.rfold(init, g)
+    }
+
+    fn all<F>(&mut self, mut predicate: F) -> bool
+        where F: FnMut(Self::Item) -> bool,
+    {
+        self.iter.rfold_while(true, move |_, elt| {
+            if predicate(elt) {
+                FoldWhile::Continue(true)
+            } else {
+                FoldWhile::Done(false)
+            }
+        })
+    }
+
+    fn any<F>(&mut self, mut predicate: F) -> bool
+        where F: FnMut(Self::Item) -> bool,
+    {
+        !self.all(move |x| !predicate(x))
+    }
+
+    fn find<F>(&mut self, mut predicate: F) -> Option<Self::Item>
+        where F: FnMut(&Self::Item) -> bool,
+    {
+        self.iter.rfold_while(None, move |_, elt| {
+            if predicate(&elt) {
+                FoldWhile::Done(Some(elt))
+            } else {
+                FoldWhile::Continue(None)
+            }
+        })
+    }
+
+    fn position<F>(&mut self, mut predicate: F) -> Option<usize>
+        where F: FnMut(Self::Item) -> bool,
+    {
+        let mut index = 0;
+        self.iter.rfold_while(None, move |_, elt| {
+            if predicate(elt) {
+                FoldWhile::Done(Some(index))
+            } else {
+                index += 1;
+                FoldWhile::Continue(None)
+            }
+        })
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RevSliceIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RevSliceIter<'a, T> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -0,0 +1,415 @@
+//! Iterators over subslices separated by elements matching a predicate
+
+use std::fmt;
+
+use iter::SliceIter;
+
+/// Iterator over subslices separated by elements that match a predicate.
+///
+/// Iterator element type is `&[T]`
+///
+/// Created with `SliceIter::split`.
+pub struct Split<'a, T: 'a, F> {
+    iter: SliceIter<'a, T>,
+    pred: F,
+    finished: bool,
+}
+
+impl<'a, T, F: Clone> Clone for Split<'a, T, F> {
+    fn clone(&self) -> Self {
+        Split {
+            iter: self.iter,
+            pred: self.pred.clone(),
+            finished: self.finished,
+        }
+    }
+}
+
+impl<'a, T, F> Split<'a, T, F> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, pred: F) -> Self {
+        Split {
+            iter,
+            pred,
+            finished: false,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, F> fmt::Debug for Split<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Split")
+            .field("iter", &self.iter)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<'a, T, F> Iterator for Split<'a, T, F>
+    where F: FnMut(&T) -> bool,
+{
+    type Item = &'a [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let rest = self.iter.as_slice();
+        match self.iter.position(&mut self.pred) {
+            Some(i) => unsafe {
+                Some(rest.get_unchecked(..i))
+            },
+            None => {
+                self.finished = true;
+                Some(rest)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.iter.len() + 1))
+        }
+    }
+}
+
+/// Iterator over subslices that end with an element matching a predicate.
+///
+/// Iterator element type is `&[T]`
+///
+/// Created with `SliceIter::split_inclusive`.
+pub struct SplitInclusive<'a, T: 'a, F> {
+    iter: SliceIter<'a, T>,
+    pred: F,
+}
+
+impl<'a, T, F: Clone> Clone for SplitInclusive<'a, T, F> {
+    fn clone(&self) -> Self {
+        SplitInclusive {
+            iter: self.iter,
+            pred: self.pred.clone(),
+        }
+    }
+}
+
+impl<'a, T, F> SplitInclusive<'a, T, F> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, pred: F) -> Self {
+        SplitInclusive {
+            iter,
+            pred,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, F> fmt::Debug for SplitInclusive<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SplitInclusive")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<'a, T, F> Iterator for SplitInclusive<'a, T, F>
+    where F: FnMut(&T) -> bool,
+{
+    type Item = &'a [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() == 0 {
+            return None;
+        }
+        let rest = self.iter.as_slice();
+        match self.iter.position(&mut self.pred) {
+            Some(i) => unsafe {
+                Some(rest.get_unchecked(..i + 1))
+            },
+            None => Some(rest),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len();
+        (if len == 0 { 0 } else { 1 }, Some(len))
+    }
+}
+
+/// Iterator over subslices separated by elements that match a predicate,
+/// without an empty subslice after a final match.
+///
+/// Iterator element type is `&[T]`
+///
+/// Created with `SliceIter::split_terminator`.
+pub struct SplitTerminator<'a, T: 'a, F> {
+    iter: SliceIter<'a, T>,
+    pred: F,
+    finished: bool,
+}
+
+impl<'a, T, F: Clone> Clone for SplitTerminator<'a, T, F> {
+    fn clone(&self) -> Self {
+        SplitTerminator {
+            iter: self.iter,
+            pred: self.pred.clone(),
+            finished: self.finished,
+        }
+    }
+}
+
+impl<'a, T, F> SplitTerminator<'a, T, F> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, pred: F) -> Self {
+        SplitTerminator {
+            iter,
+            pred,
+            finished: false,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, F> fmt::Debug for SplitTerminator<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SplitTerminator")
+            .field("iter", &self.iter)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<'a, T, F> Iterator for SplitTerminator<'a, T, F>
+    where F: FnMut(&T) -> bool,
+{
+    type Item = &'a [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let rest = self.iter.as_slice();
+        if rest.is_empty() {
+            // either the range was empty, or the last element matched
+            self.finished = true;
+            return None;
+        }
+        match self.iter.position(&mut self.pred) {
+            Some(i) => unsafe {
+                Some(rest.get_unchecked(..i))
+            },
+            None => {
+                self.finished = true;
+                Some(rest)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            let len = self.iter.len();
+            (if len == 0 { 0 } else { 1 }, Some(len))
+        }
+    }
+}
+
+/// Iterator over the non-empty subslices separated by elements that match
+/// a predicate.
+///
+/// Iterator element type is `&[T]`
+///
+/// Created with `SliceIter::split_skip_empty`.
+pub struct SplitSkipEmpty<'a, T: 'a, F> {
+    iter: SliceIter<'a, T>,
+    pred: F,
+}
+
+impl<'a, T, F: Clone> Clone for SplitSkipEmpty<'a, T, F> {
+    fn clone(&self) -> Self {
+        SplitSkipEmpty {
+            iter: self.iter,
+            pred: self.pred.clone(),
+        }
+    }
+}
+
+impl<'a, T, F> SplitSkipEmpty<'a, T, F> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, pred: F) -> Self {
+        SplitSkipEmpty {
+            iter,
+            pred,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, F> fmt::Debug for SplitSkipEmpty<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SplitSkipEmpty")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<'a, T, F> Iterator for SplitSkipEmpty<'a, T, F>
+    where F: FnMut(&T) -> bool,
+{
+    type Item = &'a [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.iter.len() != 0 {
+            let rest = self.iter.as_slice();
+            match self.iter.position(&mut self.pred) {
+                Some(0) => {}
+                Some(i) => unsafe {
+                    return Some(rest.get_unchecked(..i));
+                },
+                None => return Some(rest),
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.iter.len().div_ceil(2)))
+    }
+}
+
+/// Iterator over subslices separated by elements that match a predicate,
+/// starting from the back.
+///
+/// Iterator element type is `&[T]`
+///
+/// Created with `SliceIter::rsplit`.
+pub struct RSplit<'a, T: 'a, F> {
+    iter: SliceIter<'a, T>,
+    pred: F,
+    finished: bool,
+}
+
+impl<'a, T, F: Clone> Clone for RSplit<'a, T, F> {
+    fn clone(&self) -> Self {
+        RSplit {
+            iter: self.iter,
+            pred: self.pred.clone(),
+            finished: self.finished,
+        }
+    }
+}
+
+impl<'a, T, F> RSplit<'a, T, F> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, pred: F) -> Self {
+        RSplit {
+            iter,
+            pred,
+            finished: false,
+        }
+    }
+
+    /// Return the rest of the range without splitting, and finish.
+    fn finish(&mut self) -> Option<&'a [T]> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            Some(self.iter.as_slice())
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, F> fmt::Debug for RSplit<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RSplit")
+            .field("iter", &self.iter)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<'a, T, F> Iterator for RSplit<'a, T, F>
+    where F: FnMut(&T) -> bool,
+{
+    type Item = &'a [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let rest = self.iter.as_slice();
+        match self.iter.rposition(&mut self.pred) {
+            Some(i) => unsafe {
+                Some(rest.get_unchecked(i + 1..))
+            },
+            None => {
+                self.finished = true;
+                Some(rest)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.iter.len() + 1))
+        }
+    }
+}
+
+/// Iterator over at most `n` subslices separated by elements that match
+/// a predicate, starting from the back.
+///
+/// Iterator element type is `&[T]`
+///
+/// Created with `SliceIter::rsplitn`.
+pub struct RSplitN<'a, T: 'a, F> {
+    inner: RSplit<'a, T, F>,
+    count: usize,
+}
+
+impl<'a, T, F: Clone> Clone for RSplitN<'a, T, F> {
+    fn clone(&self) -> Self {
+        RSplitN {
+            inner: self.inner.clone(),
+            count: self.count,
+        }
+    }
+}
+
+impl<'a, T, F> RSplitN<'a, T, F> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, n: usize, pred: F) -> Self {
+        RSplitN {
+            inner: RSplit::new(iter, pred),
+            count: n,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, F> fmt::Debug for RSplitN<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RSplitN")
+            .field("inner", &self.inner)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl<'a, T, F> Iterator for RSplitN<'a, T, F>
+    where F: FnMut(&T) -> bool,
+{
+    type Item = &'a [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.inner.finish()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.count == 0 {
+            (0, Some(0))
+        } else {
+            let (low, high) = self.inner.size_hint();
+            (low, high.map(|high| high.min(self.count)))
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Owned fixed size array that iterates with `SliceIter`
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use iter::SliceIter;
+
+/// An owned array of `N` elements, held inline, that iterates using
+/// `SliceIter`.
+///
+/// This is a thin wrapper around `[T; N]` for small fixed size buffers,
+/// like the blocks and digests of hashing code. It is `Copy` when `T` is.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StackSlice<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> StackSlice<T, N> {
+    /// Return an iterator over the elements
+    pub fn iter(&self) -> SliceIter<'_, T> {
+        SliceIter::from(&self.0[..])
+    }
+
+    /// Return the equivalent slice
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Return the wrapped array
+    pub fn into_inner(self) -> [T; N] {
+        self.0
+    }
+
+    /// Move the elements into a new vector.
+    ///
+    /// This allocates exactly `N` elements.
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> Vec<T> {
+        Vec::from(self.0)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for StackSlice<T, N> {
+    fn from(array: [T; N]) -> Self {
+        StackSlice(array)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a StackSlice<T, N> {
+    type Item = &'a T;
+    type IntoIter = SliceIter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
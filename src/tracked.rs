@@ -0,0 +1,127 @@
+//! Slice iterator that remembers its original bounds
+
+use rawpointer::ptrdistance;
+
+use iter::SliceIter;
+
+/// Slice iterator that remembers its original bounds.
+///
+/// Iterator element type is `&T`
+///
+/// Created with `SliceIter::tracked`. This iterates like the `SliceIter`,
+/// keeping its unrolled `all, any, find, position`, and can report how
+/// many elements have been consumed from each end.
+#[derive(Debug)]
+pub struct TrackedSliceIter<'a, T: 'a> {
+    iter: SliceIter<'a, T>,
+    start: *const T,
+    end: *const T,
+}
+
+unsafe impl<'a, T: Sync> Send for TrackedSliceIter<'a, T> { }
+unsafe impl<'a, T: Sync> Sync for TrackedSliceIter<'a, T> { }
+
+impl<'a, T> Copy for TrackedSliceIter<'a, T> { }
+impl<'a, T> Clone for TrackedSliceIter<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> TrackedSliceIter<'a, T> {
+    pub(crate) fn new(iter: SliceIter<'a, T>) -> Self {
+        TrackedSliceIter {
+            iter,
+            start: iter.start(),
+            end: iter.end(),
+        }
+    }
+
+    /// Return the number of elements consumed from the front
+    pub fn consumed_front(&self) -> usize {
+        ptrdistance(self.start, self.iter.start())
+    }
+
+    /// Return the number of elements consumed from the back
+    pub fn consumed_back(&self) -> usize {
+        ptrdistance(self.iter.end(), self.end)
+    }
+
+    /// Return the remaining elements as a slice
+    pub fn as_slice(&self) -> &'a [T] {
+        self.iter.as_slice()
+    }
+
+    /// Return the iterator over the remaining elements, without the
+    /// original bounds
+    pub fn into_inner(self) -> SliceIter<'a, T> {
+        self.iter
+    }
+}
+
+impl<'a, T> Iterator for TrackedSliceIter<'a, T> {
+    type Item = &'a T;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.iter.len()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.iter.last()
+    }
+
+    fn fold<Acc, G>(self, init: Acc, g: G) -> Acc
+        where G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, g)
+    }
+
+    fn all<F>(&mut self, predicate: F) -> bool
+        where F: FnMut(Self::Item) -> bool,
+    {
+        self.iter.all(predicate)
+    }
+
+    fn any<F>(&mut self, predicate: F) -> bool
+        where F: FnMut(Self::Item) -> bool,
+    {
+        self.iter.any(predicate)
+    }
+
+    fn find<F>(&mut self, predicate: F) -> Option<Self::Item>
+        where F: FnMut(&Self::Item) -> bool,
+    {
+        self.iter.find(predicate)
+    }
+
+    fn position<F>(&mut self, predicate: F) -> Option<usize>
+        where F: FnMut(Self::Item) -> bool,
+    {
+        self.iter.position(predicate)
+    }
+
+    fn rposition<F>(&mut self, predicate: F) -> Option<usize>
+        where F: FnMut(Self::Item) -> bool,
+    {
+        self.iter.rposition(predicate)
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for TrackedSliceIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for TrackedSliceIter<'a, T> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -0,0 +1,121 @@
+//! Overlapping window iterators
+
+use std::slice;
+
+use iter::SliceIter;
+
+/// Iterator over overlapping windows of a `SliceIter`.
+///
+/// Iterator element type is `&[T]`
+///
+/// Created with `SliceIter::windows`.
+#[derive(Debug)]
+pub struct Windows<'a, T: 'a> {
+    iter: SliceIter<'a, T>,
+    size: usize,
+}
+
+impl<'a, T> Copy for Windows<'a, T> { }
+impl<'a, T> Clone for Windows<'a, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> Windows<'a, T> {
+    pub(crate) fn new(iter: SliceIter<'a, T>, size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        Windows {
+            iter,
+            size,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = &'a [T];
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() >= self.size {
+            unsafe {
+                let window = slice::from_raw_parts(self.iter.start(), self.size);
+                self.iter.next_unchecked();
+                Some(window)
+            }
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.iter.len() + 1).saturating_sub(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Windows<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.iter.len() >= self.size {
+            unsafe {
+                let end = self.iter.end();
+                let window = slice::from_raw_parts(end.sub(self.size), self.size);
+                self.iter.set_end(end.sub(1));
+                Some(window)
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Windows<'a, T> { }
+
+/// Iterator over overlapping windows of a `SliceIter` as array references.
+///
+/// Iterator element type is `&[T; N]`
+///
+/// Created with `SliceIter::array_windows`.
+#[derive(Debug)]
+pub struct ArrayWindows<'a, T: 'a, const N: usize> {
+    iter: SliceIter<'a, T>,
+}
+
+/// Iterator over overlapping windows of a `SliceIter` as array references.
+///
+/// Created with `SliceIter::windows_n`; this is the same type as
+/// `ArrayWindows`.
+pub type WindowsN<'a, T, const N: usize> = ArrayWindows<'a, T, N>;
+
+impl<'a, T, const N: usize> Copy for ArrayWindows<'a, T, N> { }
+impl<'a, T, const N: usize> Clone for ArrayWindows<'a, T, N> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T, const N: usize> ArrayWindows<'a, T, N> {
+    pub(crate) fn new(iter: SliceIter<'a, T>) -> Self {
+        const { assert!(N != 0, "window size must be non-zero") };
+        ArrayWindows { iter }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ArrayWindows<'a, T, N> {
+    type Item = &'a [T; N];
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() >= N {
+            unsafe {
+                let window = &*(self.iter.start() as *const [T; N]);
+                self.iter.next_unchecked();
+                Some(window)
+            }
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.iter.len() + 1).saturating_sub(N);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for ArrayWindows<'a, T, N> { }
@@ -0,0 +1,67 @@
+#![cfg(feature = "alloc")]
+
+#[macro_use]
+extern crate quickcheck;
+extern crate rawslice;
+
+use rawslice::{SliceIter, StackSlice};
+
+quickcheck! {
+    fn extend_into(prefix: Vec<u8>, v: Vec<u8>) -> bool {
+        let mut out = prefix.clone();
+        SliceIter::from(&v[..]).extend_into(&mut out);
+        out[..prefix.len()] == prefix[..] && out[prefix.len()..] == v[..]
+    }
+
+    fn extend_into_clone(v: Vec<String>) -> bool {
+        let mut out = Vec::new();
+        SliceIter::from(&v[..]).extend_into(&mut out);
+        out == v
+    }
+
+    fn to_vec(v: Vec<String>, skip: usize) -> bool {
+        let mut iter = SliceIter::from(&v[..]);
+        let skip = if v.is_empty() { 0 } else { skip % v.len() };
+        iter.nth(skip);
+        iter.to_vec() == iter.as_slice().to_vec()
+    }
+
+    fn partition_vec(v: Vec<u8>) -> bool {
+        let pred = |x: &u8| x % 3 == 0;
+        let (yes, no) = SliceIter::from(&v[..]).partition_vec(pred);
+        yes.capacity() >= v.len() && no.capacity() >= v.len() &&
+            (yes, no) == v.iter().cloned().partition(pred)
+    }
+
+    fn to_boxed_slice(v: Vec<String>, skip: usize) -> bool {
+        let mut iter = SliceIter::from(&v[..]);
+        let skip = if v.is_empty() { 0 } else { skip % v.len() };
+        iter.nth(skip);
+        *iter.to_boxed_slice() == *iter.as_slice()
+    }
+
+    fn join_into(v: Vec<String>, sep: String) -> bool {
+        let mut expected = Vec::new();
+        for (i, x) in v.iter().enumerate() {
+            if i > 0 {
+                expected.push(sep.clone());
+            }
+            expected.push(x.clone());
+        }
+        let joined = SliceIter::from(&v[..]).join_into(&sep);
+        joined.capacity() == joined.len() && joined == expected
+    }
+
+    fn stack_slice_into_vec(a: (String, String, String)) -> bool {
+        let arr = [a.0, a.1, a.2];
+        StackSlice(arr.clone()).into_vec() == arr.to_vec()
+    }
+
+    fn replace_into(v: Vec<u8>, from: u8, to: u8) -> bool {
+        // use a small alphabet so that there are matches
+        let v: Vec<u8> = v.into_iter().map(|x| x % 4).collect();
+        let from = from % 4;
+        let expected: Vec<u8> = v.iter().map(|&x| if x == from { to } else { x }).collect();
+        SliceIter::from(&v[..]).replace_into(&from, &to) == expected
+    }
+}
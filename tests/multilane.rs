@@ -0,0 +1,40 @@
+#![cfg(feature = "multilane")]
+
+#[macro_use]
+extern crate quickcheck;
+extern crate rawslice;
+
+use rawslice::SliceIter;
+
+quickcheck! {
+    fn multilane_sum_int(v: Vec<i32>) -> bool {
+        SliceIter::from(&v[..]).multilane_sum() == v.iter().sum::<i32>()
+    }
+
+    fn multilane_sum_u8(v: Vec<u8>) -> bool {
+        // small enough to not overflow
+        let v: Vec<u8> = v.into_iter().map(|x| x % 3).take(120).collect();
+        SliceIter::from(&v[..]).multilane_sum() == v.iter().sum::<u8>()
+    }
+
+    fn multilane_sum_float(v: Vec<i16>) -> bool {
+        // integral values are summed exactly by f64
+        let v: Vec<f64> = v.into_iter().map(f64::from).collect();
+        SliceIter::from(&v[..]).multilane_sum() == v.iter().sum::<f64>()
+    }
+}
+
+#[test]
+fn multilane_sum_negative_zero() {
+    let v = [-0.0f64; 3];
+    assert_eq!(SliceIter::from(&v[..]).multilane_sum().to_bits(), (-0.0f64).to_bits());
+    assert_eq!(SliceIter::from(&v[..1]).multilane_sum().to_bits(), (-0.0f64).to_bits());
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn multilane_sum_overflow() {
+    let v = [200u8; 9];
+    SliceIter::from(&v[..]).multilane_sum();
+}
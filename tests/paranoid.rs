@@ -0,0 +1,58 @@
+#![cfg(feature = "paranoid")]
+
+extern crate rawslice;
+
+use std::ptr;
+
+use rawslice::{SliceIter, SliceIterMut};
+
+#[test]
+#[should_panic]
+fn new_null() {
+    let data = [1, 2, 3];
+    unsafe {
+        SliceIter::new(ptr::null(), data.as_ptr());
+    }
+}
+
+#[test]
+#[should_panic]
+fn new_unordered() {
+    let data = [1, 2, 3];
+    let iter = SliceIter::from(&data[..]);
+    unsafe {
+        SliceIter::new(iter.end(), iter.start());
+    }
+}
+
+#[test]
+#[should_panic]
+fn new_mut_unordered() {
+    let mut data = [1, 2, 3];
+    let iter = SliceIterMut::from(&mut data[..]);
+    unsafe {
+        SliceIterMut::new(iter.end(), iter.start());
+    }
+}
+
+#[test]
+fn new_ordered() {
+    let data = [1, 2, 3];
+    let iter = unsafe {
+        SliceIter::new(data.as_ptr(), data.as_ptr().add(3))
+    };
+    assert_eq!(iter.len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "start pointer is after the end pointer")]
+fn len_unordered() {
+    let mut data = [1, 2, 3];
+    let mut iter = SliceIterMut::from(&mut data[..]);
+    unsafe {
+        let start = iter.start();
+        iter.set_start(start.add(2));
+        iter.set_end(start);
+    }
+    iter.len();
+}
@@ -1,3 +1,4 @@
+#![allow(clippy::len_zero, clippy::manual_contains)]
 
 
 #[macro_use]
@@ -5,7 +6,9 @@ extern crate quickcheck;
 
 extern crate rawslice;
 
-use rawslice::SliceIter;
+use std::collections::VecDeque;
+
+use rawslice::{SliceIter, SliceIterMut, RawSlice, Repeat, StackSlice};
 
 const MAX_OFFSET: usize = 15;
 
@@ -48,3 +51,1271 @@ quickcheck! {
         v.iter().any(|x| *x == 0) == SliceIter::from(&v[..]).any(|x| *x == 0)
     }
 }
+
+quickcheck! {
+    fn slice_iter_windows(v: Vec<i8>, off: usize, n: usize) -> bool {
+        let data = offset(&v, off);
+        let n = n % 16 + 1;
+
+        let iter = SliceIter::from(data).windows(n);
+        iter.size_hint() == data.windows(n).size_hint() &&
+            data.windows(n).eq(iter)
+    }
+
+    fn slice_iter_windows_rev(v: Vec<i8>, off: usize, n: usize) -> bool {
+        let data = offset(&v, off);
+        let n = n % 16 + 1;
+
+        let mut iter = SliceIter::from(data).windows(n);
+        let mut std_iter = data.windows(n);
+        // alternate ends
+        loop {
+            let a = iter.next_back();
+            if a != std_iter.next_back() {
+                return false;
+            }
+            let b = iter.next();
+            if b != std_iter.next() {
+                return false;
+            }
+            if a.is_none() && b.is_none() {
+                break;
+            }
+        }
+        SliceIter::from(data).windows(n).rev().eq(data.windows(n).rev())
+    }
+}
+
+quickcheck! {
+    fn slice_iter_as_chunks(v: Vec<i8>, off: usize) -> bool {
+        let data = offset(&v, off);
+        let iter = SliceIter::from(data);
+
+        let (head, tail) = iter.as_chunks::<3>();
+        let mut joined = Vec::new();
+        for chunk in head {
+            joined.extend_from_slice(chunk);
+        }
+        joined.extend_from_slice(tail);
+        tail.len() < 3 && joined == data
+    }
+}
+
+quickcheck! {
+    fn slice_iter_split_at(v: Vec<i8>, i: usize) -> bool {
+        let i = i % (v.len() + 1);
+        let (a, b) = SliceIter::from(&v[..]).split_at(i);
+        let (sa, sb) = v.split_at(i);
+        a.as_slice() == sa && b.as_slice() == sb
+    }
+}
+
+quickcheck! {
+    fn slice_iter_align_to(v: Vec<u8>, off: usize) -> bool {
+        let data = offset(&v, off);
+        let (a, b, c) = unsafe { SliceIter::from(data).align_to::<u64>() };
+        let (sa, sb, sc) = unsafe { data.align_to::<u64>() };
+
+        let aligned = (b.start() as usize) % std::mem::align_of::<u64>() == 0;
+        aligned && a.len() < 8 && c.len() < 8 &&
+            a.len() + b.len() * 8 + c.len() == data.len() &&
+            a.as_slice() == sa && b.as_slice() == sb && c.as_slice() == sc
+    }
+}
+
+quickcheck! {
+    fn slice_iter_starts_ends_with(v: Vec<u8>, off: usize, needle: Vec<u8>) -> bool {
+        let data = offset(&v, off);
+        let iter = SliceIter::from(data);
+        // also try a needle that is a true prefix/suffix
+        let end = needle.len() % (data.len() + 1);
+        let prefix = &data[..end];
+        let suffix = &data[data.len() - end..];
+
+        iter.starts_with(&needle) == data.starts_with(&needle) &&
+            iter.ends_with(&needle) == data.ends_with(&needle) &&
+            iter.starts_with(prefix) && iter.ends_with(suffix) &&
+            iter.len() == data.len()
+    }
+}
+
+quickcheck! {
+    fn slice_iter_is_sorted(v: Vec<u8>, off: usize, sort: bool) -> bool {
+        let mut v = v;
+        if sort {
+            v.sort();
+        }
+        let data = offset(&v, off);
+        let sorted = data.windows(2).all(|w| w[0] <= w[1]);
+        let iter = SliceIter::from(data);
+
+        iter.is_sorted() == sorted &&
+            iter.is_sorted_by(|a, b| a >= b) == data.windows(2).all(|w| w[0] >= w[1])
+    }
+}
+
+quickcheck! {
+    fn slice_iter_binary_search(v: Vec<u8>, off: usize, x: u8) -> bool {
+        let mut v = v;
+        v.sort();
+        let data = offset(&v, off);
+        let iter = SliceIter::from(data);
+
+        iter.partition_point(|&y| y < x) == data.partition_point(|&y| y < x) &&
+            match (iter.binary_search(&x), data.binary_search(&x)) {
+                (Ok(i), Ok(_)) => data[i] == x,
+                (Err(i), Err(j)) => i == j,
+                _ => false,
+            }
+    }
+}
+
+quickcheck! {
+    fn slice_iter_find_ptr(v: Vec<i8>, off: usize, pat: i8) -> bool {
+        let data = offset(&v, off);
+        let mut iter = SliceIter::from(data);
+
+        match (iter.find_ptr(|x| *x == pat), data.iter().position(|x| *x == pat)) {
+            (Some(p), Some(i)) => {
+                p == unsafe { data.as_ptr().add(i) } && iter.len() == data.len() - i - 1
+            }
+            (None, None) => iter.len() == 0,
+            _ => false,
+        }
+    }
+}
+
+quickcheck! {
+    fn raw_slice_roundtrip(v: Vec<i8>, off: usize) -> bool {
+        let data = offset(&v, off);
+        let raw = RawSlice::from(data);
+
+        raw.as_slice() == data && raw.len() == data.len() &&
+            raw.is_empty() == data.is_empty() &&
+            raw.into_iter().eq(data.iter())
+    }
+
+    fn raw_slice_split_at(v: Vec<i8>, off: usize, i: usize) -> bool {
+        let data = offset(&v, off);
+        let i = i % (data.len() + 1);
+        let raw = RawSlice::from(data);
+        let (a, b) = raw.split_at(i);
+        let (sa, sb) = data.split_at(i);
+        a.as_slice() == sa && b.as_slice() == sb &&
+            raw.subslice(i / 2..i).as_slice() == &data[i / 2..i] &&
+            raw.first() == data.first() && raw.last() == data.last() &&
+            raw.get(i) == data.get(i) && raw.iter().eq(data.iter())
+    }
+}
+
+quickcheck! {
+    fn slice_iter_split(v: Vec<u8>, off: usize, pat: u8) -> bool {
+        let data = offset(&v, off);
+        // use a small alphabet so that matches are common
+        let pat = pat % 4;
+        let iter = SliceIter::from(data).split(|x| *x % 4 == pat);
+        iter.eq(data.split(|x| *x % 4 == pat))
+    }
+
+    fn slice_iter_split_terminator(v: Vec<u8>, off: usize, pat: u8) -> bool {
+        let data = offset(&v, off);
+        let pat = pat % 4;
+        let mut expected: Vec<&[u8]> = data.split(|x| *x % 4 == pat).collect();
+        if expected.last().is_some_and(|s| s.is_empty()) {
+            expected.pop();
+        }
+        let iter = SliceIter::from(data).split_terminator(|x| *x % 4 == pat);
+        iter.eq(expected)
+    }
+
+    fn slice_iter_split_skip_empty(v: Vec<u8>, off: usize, pat: u8) -> bool {
+        let data = offset(&v, off);
+        let pat = pat % 4;
+        let iter = SliceIter::from(data).split_skip_empty(|x| *x % 4 == pat);
+        iter.eq(data.split(|x| *x % 4 == pat).filter(|s| !s.is_empty()))
+    }
+}
+
+quickcheck! {
+    fn slice_iter_split_inclusive(v: Vec<u8>, off: usize) -> bool {
+        let data = offset(&v, off);
+        // map a quarter of the values to newlines
+        let lines: Vec<u8> = data.iter().map(|&x| if x % 4 == 0 { b'\n' } else { x }).collect();
+        let iter = SliceIter::from(&lines[..]).split_inclusive(|&x| x == b'\n');
+        iter.eq(lines.split_inclusive(|&x| x == b'\n'))
+    }
+}
+
+quickcheck! {
+    fn slice_iter_rsplit(v: Vec<u8>, off: usize, pat: u8) -> bool {
+        let data = offset(&v, off);
+        let pat = pat % 4;
+        let iter = SliceIter::from(data).rsplit(|x| *x % 4 == pat);
+        iter.eq(data.rsplit(|x| *x % 4 == pat))
+    }
+
+    fn slice_iter_rsplitn(v: Vec<u8>, off: usize, pat: u8, n: usize) -> bool {
+        let data = offset(&v, off);
+        let pat = pat % 4;
+        let n = n % 8;
+        let iter = SliceIter::from(data).rsplitn(n, |x| *x % 4 == pat);
+        iter.eq(data.rsplitn(n, |x| *x % 4 == pat))
+    }
+}
+
+quickcheck! {
+    fn slice_iter_group_by(v: Vec<u8>, off: usize) -> bool {
+        let data = offset(&v, off);
+        let same = |a: &u8, b: &u8| a % 3 == b % 3;
+
+        let mut groups: Vec<&[u8]> = Vec::new();
+        let mut start = 0;
+        for i in 1..data.len() + 1 {
+            if i == data.len() || !same(&data[i - 1], &data[i]) {
+                groups.push(&data[start..i]);
+                start = i;
+            }
+        }
+        SliceIter::from(data).group_by(same).eq(groups)
+    }
+}
+
+quickcheck! {
+    fn slice_iter_dedup(v: Vec<u8>, off: usize) -> bool {
+        // use a small alphabet so that there are runs
+        let v: Vec<u8> = v.into_iter().map(|x| x % 3).collect();
+        let data = offset(&v, off);
+        let mut dedup = data.to_vec();
+        dedup.dedup();
+        SliceIter::from(data).dedup().eq(&dedup)
+    }
+
+    fn slice_iter_dedup_by_key(v: Vec<u8>, off: usize) -> bool {
+        let data = offset(&v, off);
+        let key = |x: &u8| x % 3;
+        let expected: Vec<&u8> = data.iter().enumerate()
+            .filter(|&(i, x)| i == 0 || key(&data[i - 1]) != key(x))
+            .map(|(_, x)| x)
+            .collect();
+        let deduped: Vec<&u8> = SliceIter::from(data).dedup_by_key(key).collect();
+        deduped.len() == expected.len() &&
+            deduped.iter().zip(&expected).all(|(a, b)| std::ptr::eq(*a, *b))
+    }
+}
+
+quickcheck! {
+    fn slice_iter_step_by_raw(v: Vec<i8>, off: usize, step: usize) -> bool {
+        let data = offset(&v, off);
+        let step = step % 12 + 1;
+        let iter = SliceIter::from(data).step_by_raw(step);
+        iter.len() == data.iter().step_by(step).len() &&
+            iter.eq(data.iter().step_by(step))
+    }
+}
+
+quickcheck! {
+    fn slice_iter_enumerate_from_start(v: Vec<i8>, off: usize, back: usize) -> bool {
+        let data = offset(&v, off);
+        let back = back % (data.len() + 1);
+        let mut iter = SliceIter::from(data).enumerate_from_start();
+        let mut std_iter = data.iter().enumerate();
+
+        iter.by_ref().rev().take(back).eq(std_iter.by_ref().rev().take(back)) &&
+            iter.eq(std_iter)
+    }
+}
+
+quickcheck! {
+    fn slice_iter_reduce(v: Vec<i8>, off: usize) -> bool {
+        let data = offset(&v, off);
+        data.iter().reduce(|a, b| a.max(b)) ==
+            SliceIter::from(data).reduce(|a, b| a.max(b)) &&
+        data.iter().reduce(|a, _| a) == SliceIter::from(data).reduce(|a, _| a)
+    }
+}
+
+quickcheck! {
+    fn slice_iter_sum_product(v: Vec<i8>, off: usize) -> bool {
+        use std::num::Wrapping;
+        let v: Vec<i32> = v.into_iter().map(|x| x as i32).collect();
+        let data = offset(&v, off);
+        let wrapping: Vec<Wrapping<i32>> = data.iter().map(|&x| Wrapping(x)).collect();
+
+        data.iter().sum::<i32>() == SliceIter::from(data).sum::<i32>() &&
+            wrapping.iter().product::<Wrapping<i32>>() ==
+                SliceIter::from(&wrapping[..]).product::<Wrapping<i32>>() &&
+            data.iter().fold(Vec::new(), |mut v, x| { v.push(x); v }) ==
+                SliceIter::from(data).fold(Vec::new(), |mut v, x| { v.push(x); v })
+    }
+}
+
+quickcheck! {
+    fn slice_iter_split_first_last(v: Vec<i8>, off: usize) -> bool {
+        let data = offset(&v, off);
+        let iter = SliceIter::from(data);
+        let first = iter.split_first().map(|(x, rest)| (x, rest.as_slice()));
+        let last = iter.split_last().map(|(x, rest)| (x, rest.as_slice()));
+        first == data.split_first() && last == data.split_last()
+    }
+}
+
+quickcheck! {
+    fn slice_iter_take_raw(v: Vec<i8>, off: usize, n: usize) -> bool {
+        let data = offset(&v, off);
+        let n = n % (data.len() + 4);
+        SliceIter::from(data).take_raw(n).eq(data.iter().take(n))
+    }
+}
+
+quickcheck! {
+    fn slice_iter_skip_raw(v: Vec<i8>, off: usize, n: usize) -> bool {
+        let data = offset(&v, off);
+        let n = n % (data.len() + 4);
+        let iter = SliceIter::from(data).skip_raw(n);
+        iter.end() == data.as_ptr_range().end && iter.eq(data.iter().skip(n))
+    }
+}
+
+quickcheck! {
+    fn slice_iter_mut_chunks_mut(v: Vec<i8>, n: usize) -> bool {
+        let n = n % 8 + 1;
+        let mut v1 = v.clone();
+        let mut v2 = v;
+        for (i, chunk) in SliceIterMut::from(&mut v1[..]).chunks_mut(n).enumerate() {
+            for x in chunk {
+                *x = x.wrapping_add(i as i8);
+            }
+        }
+        for (i, chunk) in v2.chunks_mut(n).enumerate() {
+            for x in chunk {
+                *x = x.wrapping_add(i as i8);
+            }
+        }
+        v1 == v2 &&
+            SliceIterMut::from(&mut v1[..]).chunks_mut(n).size_hint() == v2.chunks_mut(n).size_hint()
+    }
+}
+
+quickcheck! {
+    fn slice_iter_mut(v: Vec<i8>, back: usize) -> bool {
+        let mut v1 = v.clone();
+        let mut v2 = v;
+        let back = back % (v1.len() + 1);
+        let mut iter = SliceIterMut::from(&mut v1[..]);
+        for x in iter.by_ref().rev().take(back) {
+            *x = x.wrapping_mul(3);
+        }
+        for x in iter {
+            *x = x.wrapping_add(1);
+        }
+        let len = v2.len();
+        for x in &mut v2[len - back..] {
+            *x = x.wrapping_mul(3);
+        }
+        for x in &mut v2[..len - back] {
+            *x = x.wrapping_add(1);
+        }
+        v1 == v2
+    }
+}
+
+quickcheck! {
+    fn slice_iter_eq_iter(a: Vec<u8>, b: Vec<u8>, same: bool) -> bool {
+        let b = if same { a.clone() } else { b };
+        let ia = SliceIter::from(&a[..]);
+        let ib = SliceIter::from(&b[..]);
+        ia.eq_iter(ib) == (a == b) &&
+            ia.eq_by(ib, |x, y| x % 2 == y % 2) ==
+                a.iter().map(|x| x % 2).eq(b.iter().map(|x| x % 2))
+    }
+}
+
+quickcheck! {
+    fn slice_iter_position_min_max(v: Vec<u8>, off: usize) -> bool {
+        let v: Vec<u8> = v.into_iter().map(|x| x % 8).collect();
+        let data = offset(&v, off);
+        let min = data.iter().min();
+        let max = data.iter().max();
+        let iter = SliceIter::from(data);
+        iter.position_min() == min.and_then(|m| data.iter().position(|x| x == m)) &&
+            iter.position_max() == max.and_then(|m| data.iter().position(|x| x == m))
+    }
+}
+
+quickcheck! {
+    fn slice_iter_rtry_for_each(v: Vec<i8>, off: usize, pat: i8) -> bool {
+        use std::ops::ControlFlow;
+        let data = offset(&v, off);
+        let f = |x: &i8| if *x == pat { ControlFlow::Break(*x) } else { ControlFlow::Continue(()) };
+
+        let mut iter = SliceIter::from(data);
+        let mut std_iter = data.iter();
+        iter.rtry_for_each(f) == std_iter.by_ref().rev().try_for_each(f) &&
+            iter.as_slice() == std_iter.as_slice() &&
+            iter.eq(std_iter)
+    }
+}
+
+quickcheck! {
+    fn slice_iter_array_windows(v: Vec<i8>, off: usize) -> bool {
+        use std::convert::TryFrom;
+        let data = offset(&v, off);
+        let iter = SliceIter::from(data).array_windows::<3>();
+        iter.size_hint() == data.windows(3).size_hint() &&
+            iter.eq(data.windows(3).map(|w| <&[i8; 3]>::try_from(w).unwrap()))
+    }
+
+    fn slice_iter_windows_n(v: Vec<i8>, off: usize) -> bool {
+        use std::convert::TryFrom;
+        let data = offset(&v, off);
+        let two: rawslice::WindowsN<i8, 2> = SliceIter::from(data).windows_n();
+        let five = SliceIter::from(data).windows_n::<5>();
+        two.len() == data.windows(2).len() &&
+            two.copied().eq(data.windows(2).map(|w| <[i8; 2]>::try_from(w).unwrap())) &&
+            five.copied().eq(data.windows(5).map(|w| <[i8; 5]>::try_from(w).unwrap()))
+    }
+}
+
+quickcheck! {
+    fn slice_iter_rchunks(v: Vec<i8>, off: usize, n: usize) -> bool {
+        let data = offset(&v, off);
+        let n = n % 8 + 1;
+        let iter = SliceIter::from(data).rchunks(n);
+        iter.size_hint() == data.rchunks(n).size_hint() && iter.eq(data.rchunks(n))
+    }
+
+    fn slice_iter_rchunks_exact(v: Vec<i8>, off: usize, n: usize) -> bool {
+        let data = offset(&v, off);
+        let n = n % 8 + 1;
+        let iter = SliceIter::from(data).rchunks_exact(n);
+        iter.remainder() == data.rchunks_exact(n).remainder() &&
+            iter.len() == data.rchunks_exact(n).len() &&
+            iter.eq(data.rchunks_exact(n))
+    }
+}
+
+quickcheck! {
+    fn slice_iter_reversed(v: Vec<i8>, off: usize, pat: i8) -> bool {
+        let data = offset(&v, off);
+        let rev = SliceIter::from(data).reversed();
+        let mut a = rev;
+        let mut b = rev;
+        let mut c = rev;
+
+        rev.eq(data.iter().rev()) &&
+            a.position(|x| *x == pat) ==
+                data.iter().rposition(|x| *x == pat).map(|i| data.len() - 1 - i) &&
+            a.eq(data.iter().rev().skip_while(|x| **x != pat).skip(1)) &&
+            b.find(|x| **x == pat) == data.iter().rev().find(|x| **x == pat) &&
+            c.all(|x| *x != pat) == data.iter().all(|x| *x != pat) &&
+            rev.reversed().eq(data.iter())
+    }
+
+    fn slice_iter_rfold(v: Vec<i8>, off: usize) -> bool {
+        let data = offset(&v, off);
+        let push = |mut acc: Vec<i8>, &x: &i8| { acc.push(x); acc };
+        SliceIter::from(data).rfold(Vec::new(), push) ==
+            data.iter().rfold(Vec::new(), push) &&
+            SliceIter::from(data).reversed().fold(Vec::new(), push) ==
+            data.iter().rev().cloned().collect::<Vec<_>>()
+    }
+}
+
+quickcheck! {
+    fn slice_iter_last(v: Vec<i8>, off: usize, front: usize) -> bool {
+        let data = offset(&v, off);
+        let front = front % (data.len() + 1);
+        let mut iter = SliceIter::from(data);
+        let mut std_iter = data.iter();
+        for _ in 0..front {
+            iter.next();
+            std_iter.next();
+        }
+        iter.last() == std_iter.last()
+    }
+}
+
+quickcheck! {
+    fn slice_iter_mut_chunks_exact_mut(v: Vec<i8>) -> bool {
+        let mut v1 = v.clone();
+        let mut v2 = v;
+        {
+            let mut chunks = SliceIterMut::from(&mut v1[..]).chunks_exact_mut::<4>();
+            for (i, chunk) in chunks.by_ref().enumerate() {
+                chunk[0] = i as i8;
+                chunk.reverse();
+            }
+            for x in chunks.into_remainder() {
+                *x = -1;
+            }
+        }
+        {
+            let mut chunks = v2.chunks_exact_mut(4);
+            for (i, chunk) in chunks.by_ref().enumerate() {
+                chunk[0] = i as i8;
+                chunk.reverse();
+            }
+            for x in chunks.into_remainder() {
+                *x = -1;
+            }
+        }
+        v1 == v2
+    }
+}
+
+quickcheck! {
+    fn slice_iter_mut_split_at_mut(v: Vec<i8>, i: usize) -> bool {
+        let i = i % (v.len() + 1);
+        let mut v1 = v.clone();
+        let mut v2 = v;
+        {
+            let (a, b) = SliceIterMut::from(&mut v1[..]).split_at_mut(i);
+            for x in a {
+                *x = x.wrapping_add(1);
+            }
+            for x in b {
+                *x = x.wrapping_sub(1);
+            }
+        }
+        let (a, b) = v2.split_at_mut(i);
+        for x in a {
+            *x = x.wrapping_add(1);
+        }
+        for x in b {
+            *x = x.wrapping_sub(1);
+        }
+        v1 == v2
+    }
+}
+
+quickcheck! {
+    fn slice_iter_mut_compact(v: Vec<i8>) -> bool {
+        let mut v1 = v.clone();
+        let mut v2 = v;
+        let live = SliceIterMut::from(&mut v1[..]).compact(|x| x % 3 == 0);
+        v2.retain(|x| x % 3 == 0);
+        let mut rest = v1[live..].to_vec();
+        rest.sort();
+        let mut removed: Vec<i8> = v1.iter().cloned().filter(|x| x % 3 != 0).collect();
+        removed.sort();
+        v1[..live] == v2[..] && rest == removed
+    }
+
+    fn slice_iter_mut_reverse(v: Vec<i8>, i: usize, j: usize) -> bool {
+        let mut v1 = v.clone();
+        let mut v2 = v;
+        let j = j % (v1.len() + 1);
+        let i = i % (j + 1);
+        SliceIterMut::from(&mut v1[i..j]).reverse();
+        v2[i..j].reverse();
+        v1 == v2
+    }
+
+    fn slice_iter_mut_rotate(v: Vec<i8>, off: usize, k: usize) -> bool {
+        let off = if v.is_empty() { 0 } else { off % v.len() };
+        let k = k % (v.len() - off + 1);
+        let mut v1 = v.clone();
+        let mut v2 = v.clone();
+        let mut v3 = v.clone();
+        let mut v4 = v;
+        SliceIterMut::from(&mut v1[off..]).rotate_left(k);
+        v2[off..].rotate_left(k);
+        SliceIterMut::from(&mut v3[off..]).rotate_right(k);
+        v4[off..].rotate_right(k);
+        v1 == v2 && v3 == v4
+    }
+
+    fn slice_iter_mut_swap(v: Vec<i8>, a: usize, b: usize) -> bool {
+        if v.is_empty() {
+            return true;
+        }
+        let (a, b) = (a % v.len(), b % v.len());
+        let mut v1 = v.clone();
+        let mut v2 = v;
+        SliceIterMut::from(&mut v1[..]).swap(a, b);
+        v2.swap(a, b);
+        v1 == v2
+    }
+
+    fn slice_iter_mut_fill(v: Vec<String>, off: usize, x: String) -> bool {
+        let off = if v.is_empty() { 0 } else { off % v.len() };
+        let mut v1 = v.clone();
+        let mut v2 = v.clone();
+        let mut v3 = v;
+        SliceIterMut::from(&mut v1[off..]).fill(x.clone());
+        v2[off..].fill(x.clone());
+        let mut n = 0;
+        SliceIterMut::from(&mut v3[off..]).fill_with(|| { n += 1; n.to_string() });
+        v1 == v2 && v3[..off] == v2[..off] &&
+            v3[off..].iter().enumerate().all(|(i, s)| *s == (i + 1).to_string())
+    }
+}
+
+quickcheck! {
+    fn min_by_max_by(v: Vec<i8>, off: usize) -> bool {
+        let v = offset(&v, off);
+        let cmp = |a: &&i8, b: &&i8| (**a / 16).cmp(&(**b / 16));
+        let iter = SliceIter::from(v);
+        let ptr = |x: Option<&i8>| x.map(|x| x as *const i8);
+        ptr(iter.min_by(cmp)) == ptr(v.iter().min_by(cmp)) &&
+            ptr(iter.max_by(cmp)) == ptr(v.iter().max_by(cmp))
+    }
+
+    fn min_by_max_by_inconsistent(v: Vec<i8>, off: usize) -> bool {
+        // not antisymmetric: elements of different parity are always Less
+        let v = offset(&v, off);
+        let cmp = |a: &&i8, b: &&i8| {
+            if (**a ^ **b) & 1 == 0 { a.cmp(b) } else { std::cmp::Ordering::Less }
+        };
+        let iter = SliceIter::from(v);
+        let ptr = |x: Option<&i8>| x.map(|x| x as *const i8);
+        ptr(iter.min_by(cmp)) == ptr(v.iter().min_by(cmp)) &&
+            ptr(iter.max_by(cmp)) == ptr(v.iter().max_by(cmp))
+    }
+}
+
+quickcheck! {
+    fn try_reduce(v: Vec<u8>, off: usize) -> bool {
+        let v = offset(&v, off);
+        let mut iter = SliceIter::from(v);
+        // manual checked fold, recording how many elements it used
+        let mut expected = Ok(None);
+        let mut consumed = 0;
+        for &x in v {
+            consumed += 1;
+            expected = match expected {
+                Ok(None) => Ok(Some(x)),
+                Ok(Some(acc)) => acc.checked_add(x).map(Some).ok_or(()),
+                err => err,
+            };
+            if expected.is_err() {
+                break;
+            }
+        }
+        iter.try_reduce(|a, &b| a.checked_add(b).ok_or(())) == expected &&
+            iter.len() == v.len() - consumed
+    }
+}
+
+quickcheck! {
+    fn scan_raw(v: Vec<i8>, limit: i8) -> bool {
+        let prefix_sum = |acc: &mut i32, &x: &i8| {
+            *acc += x as i32;
+            if *acc > limit as i32 { None } else { Some(*acc) }
+        };
+        SliceIter::from(&v[..]).scan_raw(0, prefix_sum)
+            .eq(v.iter().scan(0, prefix_sum))
+    }
+}
+
+quickcheck! {
+    fn find_position(v: Vec<i8>, off: usize, x: i8) -> bool {
+        let v = offset(&v, off);
+        let mut iter = SliceIter::from(v);
+        match iter.find_position(|&y| y == x) {
+            Some((i, elt)) => {
+                Some(i) == v.iter().position(|&y| y == x) &&
+                    std::ptr::eq(elt, &v[i]) &&
+                    iter.len() == v.len() - i - 1
+            }
+            None => !v.contains(&x) && iter.len() == 0,
+        }
+    }
+}
+
+quickcheck! {
+    fn chunk_iters(v: Vec<i8>, n: u8) -> bool {
+        let n = n as usize % 8 + 1;
+        let chunks: Vec<_> = SliceIter::from(&v[..]).chunk_iters(n).collect();
+        chunks.iter().all(|c| c.len() == n || c.len() == v.len() % n) &&
+            chunks.into_iter().flatten().eq(v.iter())
+    }
+}
+
+quickcheck! {
+    fn take_while_raw(v: Vec<i8>, off: usize) -> bool {
+        let v = offset(&v, off);
+        let mut iter = SliceIter::from(v);
+        let mut rest = iter;
+        let prefix = iter.take_while_raw(|&x| x > 0);
+        rest.skip_while_raw(|&x| x > 0);
+        prefix.eq(v.iter().take_while(|&&x| x > 0)) &&
+            iter.eq(v.iter().skip_while(|&&x| x > 0)) &&
+            rest.as_slice() == iter.as_slice()
+    }
+}
+
+quickcheck! {
+    fn cycle_n(v: Vec<i8>, off: usize, times: u8) -> bool {
+        let v = offset(&v, off);
+        let times = times as usize % 5;
+        let iter = SliceIter::from(v).cycle_n(times);
+        let len = v.len() * times;
+        iter.size_hint() == (len, Some(len)) &&
+            iter.eq(v.iter().cycle().take(len))
+    }
+}
+
+quickcheck! {
+    fn starts_with_by(v: Vec<u8>, off: usize, needle: Vec<u8>, n: usize) -> bool {
+        let v = offset(&v, off);
+        // use a prefix of `v` as the needle half of the time
+        let needle = if n % 2 == 0 {
+            v[..n % (v.len() + 1)].iter().map(|x| x.to_ascii_uppercase()).collect()
+        } else {
+            needle
+        };
+        let eq = |a: &u8, b: &u8| a.eq_ignore_ascii_case(b);
+        let expected = needle.len() <= v.len() &&
+            v.iter().zip(&needle).all(|(a, b)| eq(a, b));
+        SliceIter::from(v).starts_with_by(&needle, eq) == expected
+    }
+}
+
+quickcheck! {
+    fn split_once(v: Vec<u8>, off: usize) -> bool {
+        let v = offset(&v, off);
+        let delim = |&x: &u8| x % 4 == 0;
+        match v.iter().position(delim) {
+            Some(i) => match SliceIter::from(v).split_once(delim) {
+                Some((head, tail)) => head == &v[..i] && tail.as_slice() == &v[i + 1..],
+                None => false,
+            },
+            None => SliceIter::from(v).split_once(delim).is_none(),
+        }
+    }
+}
+
+quickcheck! {
+    fn trim_matches(v: Vec<u8>, off: usize) -> bool {
+        let v = offset(&v, off);
+        let pad = |&x: &u8| x < 128;
+        let start = v.iter().position(|x| !pad(x)).unwrap_or(v.len());
+        let end = v.iter().rposition(|x| !pad(x)).map_or(0, |i| i + 1);
+        let iter = SliceIter::from(v);
+        iter.trim_start_matches(pad).as_slice() == &v[start..] &&
+            iter.trim_end_matches(pad).as_slice() == &v[..end] &&
+            iter.trim_matches(pad).as_slice() == &v[start..end.max(start)]
+    }
+}
+
+quickcheck! {
+    fn partial_eq_slice(v: Vec<u8>, off: usize, w: Vec<u8>) -> bool {
+        let v = offset(&v, off);
+        let w: &[u8] = if off % 2 == 0 { v } else { &w };
+        let iter = SliceIter::from(v);
+        let expected = iter.as_slice() == w;
+        [iter == w, iter == *w, w == iter, *w == iter].iter().all(|&r| r == expected)
+    }
+}
+
+quickcheck! {
+    fn run_length(v: Vec<u8>, off: usize) -> bool {
+        let v = offset(&v, off);
+        let v: Vec<u8> = v.iter().map(|x| x % 3).collect();
+        let mut expected = Vec::new();
+        for x in &v {
+            match expected.last_mut() {
+                Some(&mut (y, ref mut n)) if y == x => *n += 1,
+                _ => expected.push((x, 1)),
+            }
+        }
+        SliceIter::from(&v[..]).run_length(|a, b| a == b)
+            .map(|(x, n)| (x as *const u8, n))
+            .eq(expected.into_iter().map(|(x, n)| (x as *const u8, n)))
+    }
+}
+
+quickcheck! {
+    fn find_subslice(v: Vec<u8>, off: usize, start: usize, len: u8) -> bool {
+        // use a small alphabet so that needles are found
+        let v: Vec<u8> = offset(&v, off).iter().map(|x| x % 3).collect();
+        let start = start % (v.len() + 1);
+        let end = (start + len as usize % 5).min(v.len());
+        let needle = &v[start..end];
+        let expected = (0..v.len() + 1).find(|&i| v[i..].starts_with(needle));
+        SliceIter::from(&v[..]).find_subslice(needle) == expected
+    }
+}
+
+quickcheck! {
+    fn rsplit_once(v: Vec<u8>, off: usize) -> bool {
+        let v: Vec<u8> = offset(&v, off).iter().map(|x| b"a/b"[*x as usize % 3]).collect();
+        let s = std::str::from_utf8(&v).unwrap();
+        match (s.rsplit_once('/'), SliceIter::from(&v[..]).rsplit_once(|&x| x == b'/')) {
+            (Some((head, tail)), Some((h, t))) => h == head.as_bytes() && t == tail.as_bytes(),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+quickcheck! {
+    fn inspect_raw(v: Vec<u8>, off: usize, x: u8) -> bool {
+        let v = offset(&v, off);
+        let mut visited = Vec::new();
+        let found = SliceIter::from(v).inspect_raw(|&y| visited.push(y)).position(|&y| y == x);
+        let mut expected = Vec::new();
+        let expected_found = v.iter().inspect(|&&y| expected.push(y)).position(|&y| y == x);
+        let mut all = Vec::new();
+        SliceIter::from(v).inspect_raw(|&y| all.push(y)).fold((), |(), _| ());
+        found == expected_found && visited == expected && all == v
+    }
+}
+
+quickcheck! {
+    fn map_raw(v: Vec<(u8, i8)>, off: usize, x: u8) -> bool {
+        let v = offset(&v, off);
+        let f = |pair: &(u8, i8)| pair.0 / 4;
+        let mut iter = SliceIter::from(v).map_raw(f);
+        let mut std_iter = v.iter().map(f);
+        iter.position(|y| y == x) == std_iter.position(|y| y == x) &&
+            iter.find(|&y| y > x) == std_iter.find(|&y| y > x) &&
+            iter.len() == std_iter.len() &&
+            SliceIter::from(v).map_raw(f).rev().eq(v.iter().map(f).rev())
+    }
+}
+
+quickcheck! {
+    fn tracked(v: Vec<i8>, off: usize, ends: Vec<bool>) -> bool {
+        let v = offset(&v, off);
+        let mut iter = SliceIter::from(v).tracked();
+        let (mut front, mut back) = (0, 0);
+        for from_back in ends {
+            let elt = if from_back { iter.next_back() } else { iter.next() };
+            if elt.is_some() {
+                if from_back { back += 1 } else { front += 1 }
+            }
+        }
+        iter.consumed_front() == front && iter.consumed_back() == back &&
+            iter.as_slice() == &v[front..v.len() - back]
+    }
+
+    fn offset_cursor_resume_at(v: Vec<u32>, off: usize, steps: u8, to: usize) -> bool {
+        use rawslice::SliceIterError;
+
+        let v = offset(&v, off);
+        let mut cursor = SliceIter::from(v).offset_cursor();
+        let steps = steps as usize % (v.len() + 1);
+        for _ in 0..steps {
+            cursor.next();
+        }
+        let consumed = cursor.consumed();
+        let to = to % (4 * v.len() + 8);
+        let res = cursor.resume_at(to);
+        consumed == steps * 4 && match res {
+            Ok(()) => {
+                to % 4 == 0 && cursor.consumed() == to &&
+                    cursor.as_slice() == &v[to / 4..]
+            }
+            Err(SliceIterError::SizeNotMultiple) => {
+                to % 4 != 0 && cursor.consumed() == consumed
+            }
+            Err(SliceIterError::OutOfBounds) => {
+                to / 4 > v.len() && cursor.consumed() == consumed
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+quickcheck! {
+    fn exact_size_adaptors(v: Vec<i8>, off: usize, n: usize) -> bool {
+        let data = offset(&v, off);
+        let n = n % 8 + 1;
+        let iter = SliceIter::from(data);
+        let mut v1 = data.to_vec();
+        let mut v2 = data.to_vec();
+
+        iter.windows(n).len() == iter.windows(n).count() &&
+            iter.array_windows::<3>().len() == iter.array_windows::<3>().count() &&
+            iter.rchunks(n).len() == iter.rchunks(n).count() &&
+            iter.chunk_iters(n).len() == iter.chunk_iters(n).count() &&
+            iter.chunk_iters(n).len() == data.chunks(n).len() &&
+            SliceIterMut::from(&mut v1[..]).chunks_mut(n).len() == data.chunks(n).count() &&
+            SliceIterMut::from(&mut v2[..]).chunks_exact_mut::<3>().len() == data.len() / 3
+    }
+}
+
+quickcheck! {
+    fn by_key_with(v: Vec<i8>, off: usize) -> bool {
+        let v = offset(&v, off);
+        let f = |x: &i8| *x / 8;
+        let iter = SliceIter::from(v);
+        let check = |found: Option<(i8, &i8)>, expected: Option<&i8>| {
+            match (found, expected) {
+                (Some((key, elt)), Some(e)) => std::ptr::eq(elt, e) && key == f(elt),
+                (None, None) => true,
+                _ => false,
+            }
+        };
+        check(iter.max_by_key_with(f), v.iter().max_by_key(|x| f(x))) &&
+            check(iter.min_by_key_with(f), v.iter().min_by_key(|x| f(x)))
+    }
+}
+
+quickcheck! {
+    fn split_at_checked(v: Vec<i8>, off: usize, i: usize, j: usize) -> bool {
+        let v = offset(&v, off);
+        let i = i % (v.len() + 4);
+        let j = j % (v.len() + 4);
+        let iter = SliceIter::from(v);
+        let split_ok = match iter.split_at_checked(i) {
+            Some((a, b)) => i <= v.len() && a == v[..i] && b == v[i..],
+            None => i > v.len(),
+        };
+        let sub_ok = match iter.subslice_checked(i..j) {
+            Some(s) => s == v[i..j],
+            None => v.get(i..j).is_none(),
+        };
+        split_ok && sub_ok
+    }
+}
+
+quickcheck! {
+    fn enumerate_fold_while(v: Vec<i8>, off: usize, x: i8) -> bool {
+        use rawslice::FoldWhile;
+        let v = offset(&v, off);
+        let mut iter = SliceIter::from(v);
+        let mut visited = Vec::new();
+        let found = iter.enumerate_fold_while(None, |_, i, &y| {
+            visited.push((i, y));
+            if y == x { FoldWhile::Done(Some(i)) } else { FoldWhile::Continue(None) }
+        });
+        let expected = v.iter().position(|&y| y == x);
+        let end = expected.map_or(v.len(), |i| i + 1);
+        found == expected && SliceIter::from(v).position(|&y| y == x) == expected &&
+            visited.into_iter().eq(v[..end].iter().cloned().enumerate())
+    }
+}
+
+quickcheck! {
+    fn all_eq_any_eq(v: Vec<u8>, off: usize, x: u8) -> bool {
+        let v: Vec<u8> = offset(&v, off).iter().map(|y| y % 2).collect();
+        let x = x % 2;
+        SliceIter::from(&v[..]).all_eq(&x) == v.iter().all(|&y| y == x) &&
+            SliceIter::from(&v[..]).any_eq(&x) == v.contains(&x)
+    }
+}
+
+quickcheck! {
+    fn next_chunk(v: Vec<i8>, off: usize) -> bool {
+        let v = offset(&v, off);
+        let mut iter = SliceIter::from(v);
+        match iter.next_chunk::<3>() {
+            Ok(chunk) => {
+                chunk.iter().zip(&v[..3]).all(|(&a, b)| std::ptr::eq(a, b)) &&
+                    iter == v[3..]
+            }
+            Err(rest) => v.len() < 3 && rest == *v && iter == *v,
+        }
+    }
+}
+
+quickcheck! {
+    fn pairs_mut(v: Vec<i8>) -> bool {
+        let mut v1 = v.clone();
+        let mut v2 = v;
+        let mut pairs = SliceIterMut::from(&mut v1[..]).pairs_mut();
+        for (a, b) in &mut pairs {
+            std::mem::swap(a, b);
+            *a = a.wrapping_add(*b);
+        }
+        if let Some(x) = pairs.into_remainder() {
+            *x = 0;
+        }
+        let mut chunks = v2.chunks_exact_mut(2);
+        for chunk in &mut chunks {
+            chunk.swap(0, 1);
+            chunk[0] = chunk[0].wrapping_add(chunk[1]);
+        }
+        for x in chunks.into_remainder() {
+            *x = 0;
+        }
+        v1 == v2
+    }
+}
+
+quickcheck! {
+    fn positions(v: Vec<u8>, off: usize) -> bool {
+        let v = offset(&v, off);
+        let pred = |&x: &u8| x % 3 == 0;
+        SliceIter::from(v).positions(pred)
+            .eq(v.iter().enumerate().filter(|&(_, x)| pred(x)).map(|(i, _)| i))
+    }
+}
+
+quickcheck! {
+    fn for_each_chunk(v: Vec<i8>, off: usize, n: u8) -> bool {
+        let v = offset(&v, off);
+        let n = n as usize % 8 + 1;
+        let mut chunks = Vec::new();
+        SliceIter::from(v).for_each_chunk(n, |c| chunks.push(c));
+        chunks.into_iter().eq(v.chunks(n))
+    }
+
+    fn for_each_window(v: Vec<i8>, off: usize, n: u8) -> bool {
+        let v = offset(&v, off);
+        let n = n as usize % 8 + 1;
+        let mut windows = Vec::new();
+        SliceIter::from(v).for_each_window(n, |w| windows.push(w));
+        windows.into_iter().eq(v.windows(n))
+    }
+
+    fn any_window(v: Vec<u8>, off: usize, n: u8, sig: (u8, u8)) -> bool {
+        // use a small alphabet so that there are matches
+        let v: Vec<u8> = offset(&v, off).iter().map(|x| x % 3).collect();
+        let n = n as usize % 4 + 1;
+        let sig = [sig.0 % 3, sig.1 % 3];
+        let pred = |w: &[u8]| w.starts_with(&sig[..n.min(2)]);
+        SliceIter::from(&v[..]).any_window(n, pred) == v.windows(n).any(pred)
+    }
+}
+
+quickcheck! {
+    fn interleave(a: Vec<i8>, b: Vec<i8>) -> bool {
+        let mut expected: Vec<&i8> = Vec::new();
+        for i in 0..a.len().max(b.len()) {
+            expected.extend(a.get(i));
+            expected.extend(b.get(i));
+        }
+        let iter = SliceIter::from(&a[..]).interleave(SliceIter::from(&b[..]));
+        iter.len() == expected.len() && iter.eq(expected)
+    }
+}
+
+quickcheck! {
+    fn cmp_iter(a: Vec<u8>, b: Vec<u8>, off: usize) -> bool {
+        // make common prefixes likely
+        let a: Vec<u8> = a.iter().map(|x| x % 2).collect();
+        let b: Vec<u8> = b.iter().map(|x| x % 2).collect();
+        let b = offset(&b, off);
+        let fa: Vec<f32> = a.iter().map(|&x| if x == 0 { f32::NAN } else { x as f32 }).collect();
+        let fb: Vec<f32> = b.iter().map(|&x| x as f32).collect();
+        let (ia, ib) = (SliceIter::from(&a[..]), SliceIter::from(b));
+        ia.cmp_iter(&ib) == a[..].cmp(b) &&
+            ia.partial_cmp_iter(&ib) == a[..].partial_cmp(b) &&
+            SliceIter::from(&fa[..]).partial_cmp_iter(&SliceIter::from(&fb[..])) ==
+                fa[..].partial_cmp(&fb[..])
+    }
+}
+
+quickcheck! {
+    fn repeat(x: i8, n: u8, skip: u8) -> bool {
+        let n = n as usize;
+        let mut iter = Repeat::new(&x, n);
+        let all_x = iter.len() == n && iter.all(|&y| y == x) && iter.len() == 0;
+        let mut iter = Repeat::new(&x, n);
+        let nth = iter.nth(skip as usize);
+        all_x && Repeat::new(&x, n).rev().count() == n &&
+            nth.is_some() == (n > skip as usize) &&
+            iter.len() == n.saturating_sub(skip as usize + 1)
+    }
+
+    fn counted_filter(v: Vec<i8>, off: usize, x: i8) -> bool {
+        let v = offset(&v, off);
+        SliceIter::from(v).counted_filter(|&y| y < x) ==
+            (v.iter().filter(|&&y| y < x).count(), v.len())
+    }
+
+    fn min_max(v: Vec<u8>, off: usize) -> bool {
+        // few distinct values, so that ties are common; pointer identity
+        // checks the tie-breaking
+        let v: Vec<u8> = offset(&v, off).iter().map(|x| x % 4).collect();
+        match SliceIter::from(&v[..]).min_max() {
+            None => v.is_empty(),
+            Some((min, max)) => {
+                std::ptr::eq(min, v.iter().min().unwrap()) &&
+                    std::ptr::eq(max, v.iter().max().unwrap())
+            }
+        }
+    }
+
+    fn nth_back_checked(v: Vec<i8>, off: usize, ops: Vec<u8>) -> bool {
+        let v = offset(&v, off);
+        let mut iter = SliceIter::from(v);
+        let mut std_iter = v.iter();
+        for op in ops {
+            let n = op as usize % 4;
+            let same = match op % 3 {
+                0 => iter.next_back() == std_iter.next_back(),
+                1 => iter.nth_back_checked(n) == std_iter.nth_back(n),
+                _ => {
+                    let len = iter.len();
+                    match iter.split_off_nth_back(n) {
+                        Ok(x) => Some(x) == std_iter.nth_back(n),
+                        Err(k) => k == len && std_iter.nth_back(n).is_none(),
+                    }
+                }
+            };
+            if !same || iter.as_slice() != std_iter.as_slice() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn take_exact(v: Vec<i8>, off: usize, k: u8) -> bool {
+        let v = offset(&v, off);
+        let k = k as usize % 5 + 1;
+        let mut iter = SliceIter::from(v);
+        let mut records = Vec::new();
+        while let Some(record) = iter.take_exact(k) {
+            records.push(record);
+        }
+        let before = iter.as_slice();
+        iter.take_exact(k).is_none() && iter.as_slice() == before &&
+            records.into_iter().eq(v.chunks_exact(k)) &&
+            before == v.chunks_exact(k).remainder()
+    }
+
+    fn remaining_size_hint(v: Vec<i8>, off: usize, back: u8) -> bool {
+        let v = offset(&v, off);
+        let mut iter = SliceIter::from(v);
+        for _ in 0..back % 4 {
+            iter.next_back();
+        }
+        let (lo, hi) = iter.size_hint();
+        iter.remaining() == lo && iter.exact_len() == lo && Some(lo) == hi
+    }
+
+    fn truncate(v: Vec<i8>, off: usize, k: u8) -> bool {
+        let v = offset(&v, off);
+        let k = k as usize % (v.len() + 3);
+        let mut iter = SliceIter::from(v);
+        iter.truncate(k);
+        iter.as_slice() == &v[..k.min(v.len())]
+    }
+
+    fn get_range(v: Vec<i8>, off: usize, i: u8, j: u8) -> bool {
+        let v = offset(&v, off);
+        let iter = SliceIter::from(v);
+        let (i, j) = (i as usize % (v.len() + 3), j as usize % (v.len() + 3));
+        let same = |r: Option<SliceIter<i8>>, s: Option<&[i8]>| {
+            r.map(|r| r.as_slice()) == s
+        };
+        same(iter.get_range(i..j), v.get(i..j)) &&
+            same(iter.get_range(i..=j), v.get(i..=j)) &&
+            same(iter.get_range(i..), v.get(i..)) &&
+            same(iter.get_range(..j), v.get(..j)) &&
+            same(iter.get_range(..=j), v.get(..=j)) &&
+            same(iter.get_range(..), v.get(..)) &&
+            (iter.get_range(i..j).is_some() == (i <= j && j <= v.len()))
+    }
+
+    fn position_max_min_by_key(v: Vec<i8>, off: usize) -> bool {
+        // std's max_by_key and min_by_key break ties like itertools'
+        // position_max_by_key and position_min_by_key
+        let v = offset(&v, off);
+        let key = |x: &i8| x / 32;
+        let iter = SliceIter::from(v);
+        iter.position_max_by_key(key) ==
+            v.iter().enumerate().max_by_key(|&(_, x)| key(x)).map(|(i, _)| i) &&
+            iter.position_min_by_key(key) ==
+            v.iter().enumerate().min_by_key(|&(_, x)| key(x)).map(|(i, _)| i)
+    }
+
+    fn stack_slice_sum(a: (u16, u16, u16, u16, u16)) -> bool {
+        let arr = [a.0, a.1, a.2, a.3, a.4];
+        StackSlice(arr).iter().map(|&x| x as u32).sum::<u32>() ==
+            arr.iter().map(|&x| x as u32).sum::<u32>()
+    }
+}
+
+// Model based check of the pointer arithmetic: apply a sequence of
+// stepping and splitting operations to both a `SliceIter` and a `VecDeque`
+// of references, and compare them after each step.
+
+fn same_elt(a: Option<&u16>, b: Option<&u16>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => std::ptr::eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn model_step<'a>(iter: &mut SliceIter<'a, u16>, model: &mut VecDeque<&'a u16>,
+                  op: u8, n: u8) -> bool
+{
+    let n = n as usize % 6;
+    let len = model.len();
+    match op % 8 {
+        0 => same_elt(iter.next(), model.pop_front()),
+        1 => same_elt(iter.next_back(), model.pop_back()),
+        2 => {
+            model.drain(..n.min(len));
+            same_elt(iter.nth(n), model.pop_front())
+        }
+        3 => {
+            model.drain(len - n.min(len)..);
+            same_elt(iter.nth_back(n), model.pop_back())
+        }
+        4 => {
+            match iter.split_off_nth(n) {
+                Ok(x) => {
+                    model.drain(..n);
+                    same_elt(Some(x), model.pop_front())
+                }
+                Err(k) => {
+                    model.clear();
+                    k == len && n >= len
+                }
+            }
+        }
+        5 => {
+            match iter.split_off_nth_back(n) {
+                Ok(x) => {
+                    model.drain(len - n..);
+                    same_elt(Some(x), model.pop_back())
+                }
+                Err(k) => {
+                    model.clear();
+                    k == len && n >= len
+                }
+            }
+        }
+        6 => {
+            // split and keep one of the halves
+            let i = n.min(len);
+            let (a, b) = iter.split_at(i);
+            if (op / 8) % 2 == 0 {
+                *iter = a;
+                model.truncate(i);
+            } else {
+                *iter = b;
+                model.drain(..i);
+            }
+            true
+        }
+        _ => {
+            let (front, back) = (n % 3, n / 3);
+            iter.shrink(front, back);
+            model.drain(..front.min(len));
+            let rest = model.len();
+            model.drain(rest - back.min(rest)..);
+            true
+        }
+    }
+}
+
+quickcheck! {
+    fn model_sequence(v: Vec<u16>, off: usize, ops: Vec<(u8, u8)>) -> bool {
+        let v = offset(&v, off);
+        let mut iter = SliceIter::from(v);
+        let mut model: VecDeque<&u16> = v.iter().collect();
+        for (op, n) in ops {
+            if !model_step(&mut iter, &mut model, op, n) {
+                return false;
+            }
+            let slice = iter.as_slice();
+            let len = model.len();
+            if iter.len() != len || iter.size_hint() != (len, Some(len)) ||
+                slice.len() != len ||
+                !slice.iter().zip(&model).all(|(a, &b)| std::ptr::eq(a, b))
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "rayon")]
+
+extern crate rawslice;
+extern crate rayon;
+
+use rawslice::{SliceIter, SliceIterMut};
+use rayon::prelude::*;
+
+#[test]
+fn par_sum() {
+    let data: Vec<u64> = (0..100_000).collect();
+    let serial: u64 = data.iter().sum();
+    let parallel: u64 = SliceIter::from(&data[..]).into_par_iter().sum();
+    assert_eq!(serial, parallel);
+}
+
+#[test]
+fn par_collect_order() {
+    let data: Vec<u32> = (0..10_000).collect();
+    let v: Vec<&u32> = SliceIter::from(&data[..]).into_par_iter().collect();
+    assert!(v.into_iter().eq(data.iter()));
+}
+
+#[test]
+fn par_iter_mut() {
+    let mut serial: Vec<u32> = (0..100_000).collect();
+    let mut parallel = serial.clone();
+    for x in &mut serial {
+        *x += 1;
+    }
+    SliceIterMut::from(&mut parallel[..]).into_par_iter().for_each(|x| *x += 1);
+    assert_eq!(serial, parallel);
+}
@@ -0,0 +1,16 @@
+#![cfg(feature = "serde")]
+
+extern crate rawslice;
+extern crate serde_json;
+
+use rawslice::SliceIter;
+
+#[test]
+fn serialize() {
+    let data = [1, 2, 3, 4];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.next();
+    assert_eq!(serde_json::to_string(&iter).unwrap(),
+               serde_json::to_string(&data[1..]).unwrap());
+    assert_eq!(serde_json::to_string(&SliceIter::<u8>::default()).unwrap(), "[]");
+}
@@ -0,0 +1,805 @@
+
+extern crate rawslice;
+
+use rawslice::{SliceIter, SliceIterMut};
+
+#[test]
+#[should_panic]
+fn windows_zero() {
+    SliceIter::from(&[1, 2, 3][..]).windows(0);
+}
+
+#[test]
+fn as_chunks() {
+    let data = [1, 2, 3, 4, 5, 6, 7];
+    let (head, tail) = SliceIter::from(&data[..]).as_chunks::<2>();
+    assert_eq!(head, &[[1, 2], [3, 4], [5, 6]]);
+    assert_eq!(tail, &[7]);
+
+    let (head, tail) = SliceIter::from(&data[..]).as_chunks::<8>();
+    assert!(head.is_empty());
+    assert_eq!(tail, &data[..]);
+}
+
+#[test]
+#[should_panic]
+fn as_chunks_zero() {
+    SliceIter::from(&[1, 2, 3][..]).as_chunks::<0>();
+}
+
+#[test]
+fn cast() {
+    let data = [0x01020304u32, 0x05060708];
+    let bytes = unsafe { SliceIter::from(&data[..]).cast::<u8>().unwrap() };
+    assert_eq!(bytes.len(), 8);
+
+    let words = unsafe { bytes.cast::<u32>().unwrap() };
+    assert_eq!(words.as_slice(), &data[..]);
+}
+
+#[test]
+fn cast_fails() {
+    use rawslice::SliceIterError;
+
+    let data = [0u32; 4];
+    let mut bytes = unsafe { SliceIter::from(&data[..]).cast::<u8>().unwrap() };
+
+    // odd length
+    let (odd, _) = bytes.split_at(7);
+    assert_eq!(unsafe { odd.cast::<u32>() }.unwrap_err(), SliceIterError::SizeNotMultiple);
+
+    // misaligned start
+    bytes.next();
+    let (misaligned, _) = bytes.split_at(8);
+    assert_eq!(unsafe { misaligned.cast::<u32>() }.unwrap_err(), SliceIterError::Misaligned);
+}
+
+#[test]
+fn align_to_checked() {
+    use rawslice::SliceIterError;
+
+    let data = [0u64; 4];
+    let mut bytes = unsafe { SliceIter::from(&data[..]).cast::<u8>().unwrap() };
+    bytes.next();
+    let (prefix, middle, suffix) = unsafe { bytes.align_to_checked::<u64>().unwrap() };
+    assert_eq!((prefix.len(), middle.len(), suffix.len()), (7, 3, 0));
+
+    // 30 bytes at an odd address
+    let (odd, _) = bytes.split_at(30);
+
+    // u16 does not divide into [u8; 3]
+    let triples = unsafe { odd.cast::<[u8; 3]>().unwrap() };
+    assert_eq!(unsafe { triples.align_to_checked::<u16>() }.unwrap_err(),
+               SliceIterError::SizeNotMultiple);
+
+    // pairs of bytes at an odd address can never be aligned for u64
+    let pairs = unsafe { odd.cast::<[u8; 2]>().unwrap() };
+    assert_eq!(unsafe { pairs.align_to_checked::<u64>() }.unwrap_err(),
+               SliceIterError::Misaligned);
+
+    // narrowing splits without prefix or suffix
+    let words = [1u32, 2, 3];
+    let (prefix, middle, suffix) = unsafe {
+        SliceIter::from(&words[..]).align_to_checked::<u8>().unwrap()
+    };
+    assert_eq!((prefix.len(), middle.len(), suffix.len()), (0, 12, 0));
+    let (prefix, middle, suffix) = unsafe {
+        SliceIter::from(&data[..]).align_to_checked::<u16>().unwrap()
+    };
+    assert_eq!((prefix.len(), middle.len(), suffix.len()), (0, 16, 0));
+}
+
+#[test]
+fn set_start_end() {
+    let data = [1, 2, 3, 4, 5];
+    let mut iter = SliceIter::from(&data[..]);
+    unsafe {
+        iter.set_start(data.as_ptr().add(1));
+        iter.set_end(data.as_ptr().add(4));
+    }
+    assert_eq!(iter.as_slice(), &[2, 3, 4]);
+    assert_eq!(iter.next_back(), Some(&4));
+}
+
+#[test]
+fn as_ref_borrow() {
+    use std::borrow::Borrow;
+
+    fn sum<S: AsRef<[u8]>>(s: S) -> u32 {
+        s.as_ref().iter().map(|&x| x as u32).sum()
+    }
+
+    let data = [1u8, 2, 3];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.next();
+    assert_eq!(sum(iter), 5);
+    let b: &[u8] = iter.borrow();
+    assert_eq!(b, &[2, 3]);
+}
+
+#[test]
+fn split_trailing() {
+    let data = b",a,,b,";
+    let parts: Vec<_> = SliceIter::from(&data[..]).split(|&x| x == b',').collect();
+    assert_eq!(parts, vec![&b""[..], b"a", b"", b"b", b""]);
+}
+
+#[test]
+fn into_slice() {
+    fn tail<'a>(mut iter: SliceIter<'a, u8>) -> &'a [u8] {
+        iter.next();
+        iter.into_slice()
+    }
+
+    let data = [1, 2, 3];
+    let rest = {
+        let iter = SliceIter::from(&data[..]);
+        tail(iter)
+    };
+    assert_eq!(rest, &[2, 3]);
+}
+
+#[test]
+fn next_back_unchecked() {
+    let data = [1, 2, 3, 4];
+    let mut iter = SliceIter::from(&data[..]);
+    let mut rev = Vec::new();
+    for _ in 0..iter.len() {
+        rev.push(*unsafe { iter.next_back_unchecked() });
+    }
+    assert_eq!(rev, [4, 3, 2, 1]);
+    assert_eq!(iter.len(), 0);
+}
+
+#[test]
+fn split_first_last_unchecked() {
+    let data = [1, 2, 3];
+    let iter = SliceIter::from(&data[..]);
+    unsafe {
+        let (first, rest) = iter.split_first_unchecked();
+        assert_eq!((first, rest.as_slice()), (&1, &[2, 3][..]));
+        let (last, rest) = rest.split_last_unchecked();
+        assert_eq!((last, rest.as_slice()), (&3, &[2][..]));
+    }
+}
+
+#[test]
+#[should_panic]
+fn chunks_mut_zero() {
+    SliceIterMut::from(&mut [1, 2, 3][..]).chunks_mut(0);
+}
+
+#[test]
+fn index_mut() {
+    let mut data = [1, 2, 3];
+    {
+        let mut iter = SliceIterMut::from(&mut data[..]);
+        iter.next();
+        iter[0] = 10;
+        iter[1] += 10;
+        assert_eq!(iter[1], 13);
+    }
+    assert_eq!(data, [1, 10, 13]);
+}
+
+#[test]
+#[should_panic]
+fn index_mut_out_of_bounds() {
+    let mut data = [1, 2, 3];
+    let mut iter = SliceIterMut::from(&mut data[..]);
+    iter.next_back();
+    iter[2] = 0;
+}
+
+#[test]
+fn index_range() {
+    let data = [0, 1, 2, 3, 4, 5];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.next();
+    assert_eq!(&iter[1..3], &[2, 3]);
+    assert_eq!(&iter[3..], &[4, 5]);
+    assert_eq!(&iter[..2], &[1, 2]);
+    assert_eq!(&iter[..], &[1, 2, 3, 4, 5]);
+    assert_eq!(&iter[1..=2], &[2, 3]);
+    assert_eq!(&iter[..=0], &[1]);
+    assert_eq!(iter[0], 1);
+}
+
+#[test]
+#[should_panic]
+fn index_range_out_of_bounds() {
+    let data = [0, 1, 2];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.next();
+    let _ = &iter[1..3];
+}
+
+#[test]
+fn ptrs() {
+    let data = [1u32, 2, 3, 4];
+    let start = data.as_ptr() as usize;
+    let ptrs = SliceIter::from(&data[..]).ptrs();
+    assert_eq!(ptrs.len(), 4);
+    let addrs: Vec<_> = ptrs.map(|p| p as usize - start).collect();
+    assert_eq!(addrs, [0, 4, 8, 12]);
+    let last = SliceIter::from(&data[..]).ptrs().next_back();
+    assert_eq!(last, Some(&data[3] as *const u32));
+}
+
+#[test]
+#[should_panic]
+fn rchunks_zero() {
+    SliceIter::from(&[1, 2, 3][..]).rchunks(0);
+}
+
+#[test]
+fn copy_to_slice() {
+    let data = [1, 2, 3, 4];
+    let mut dst = [0; 3];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.next();
+    iter.copy_to_slice(&mut dst);
+    assert_eq!(dst, [2, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn copy_to_slice_mismatch() {
+    let data = [1, 2, 3, 4];
+    let mut dst = [0; 3];
+    SliceIter::from(&data[..]).copy_to_slice(&mut dst);
+}
+
+#[test]
+fn copy_to_ptr() {
+    let data = [1, 2, 3, 4];
+    let mut dst = [0; 3];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.next();
+    unsafe {
+        iter.copy_to_ptr(dst.as_mut_ptr());
+    }
+    assert_eq!(dst, [2, 3, 4]);
+}
+
+#[test]
+fn copy_within_overlapping() {
+    let mut data = [0, 1, 2, 3, 4, 5];
+    let mut iter = SliceIterMut::from(&mut data[..]);
+    iter.next();
+    iter.copy_within(0..3, 2);
+    assert_eq!(data, [0, 1, 2, 1, 2, 3]);
+
+    let mut data = [0, 1, 2, 3, 4, 5];
+    SliceIterMut::from(&mut data[..]).copy_within(2..5, 0);
+    assert_eq!(data, [2, 3, 4, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic]
+fn copy_within_out_of_bounds() {
+    let mut data = [0, 1, 2, 3];
+    SliceIterMut::from(&mut data[..]).copy_within(1..3, 3);
+}
+
+#[test]
+fn new_checked() {
+    use rawslice::SliceIterError;
+    use std::ptr;
+
+    let data = [1u32, 2, 3];
+    let start = data.as_ptr();
+    unsafe {
+        let end = start.add(3);
+        let iter = SliceIter::new_checked(start, end).unwrap();
+        assert_eq!(iter.as_slice(), &data[..]);
+
+        assert_eq!(SliceIter::new_checked(ptr::null(), end).unwrap_err(), SliceIterError::Null);
+        assert_eq!(SliceIter::new_checked(start, ptr::null()).unwrap_err(), SliceIterError::Null);
+        assert_eq!(SliceIter::new_checked(end, start).unwrap_err(), SliceIterError::Unordered);
+        let odd_end = (end as *const u8).sub(1) as *const u32;
+        assert_eq!(SliceIter::new_checked(start, odd_end).unwrap_err(),
+                   SliceIterError::SizeNotMultiple);
+        let odd_start = (start as *const u8).add(1) as *const u32;
+        let odd_end = (end as *const u8).add(1) as *const u32;
+        assert_eq!(SliceIter::new_checked(odd_start, odd_end).unwrap_err(),
+                   SliceIterError::Misaligned);
+    }
+}
+
+#[test]
+fn cursor() {
+    let data = [1, 2, 3, 4, 5];
+    let mut iter = SliceIter::from(&data[..]);
+    assert_eq!(iter.cursor().take(2).count(), 2);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.cursor().position(|&x| x == 4), Some(1));
+    assert_eq!(iter.as_slice(), &[5]);
+}
+
+#[test]
+fn as_mut_ptr_range() {
+    let mut data = [1u32, 2, 3, 4];
+    let mut iter = SliceIterMut::from(&mut data[..]);
+    iter.next();
+    let range = iter.as_mut_ptr_range();
+    assert_eq!(range.end as usize - range.start as usize, iter.len() * 4);
+    unsafe {
+        *iter.as_mut_ptr() = 10;
+        *range.end.sub(1) = 40;
+    }
+    assert_eq!(iter.as_slice(), &[10, 3, 40]);
+    unsafe {
+        *iter.end_mut() = range.end.sub(1);
+        *iter.start_mut() = range.start.add(1);
+    }
+    assert_eq!(iter.as_slice(), &[3]);
+}
+
+#[test]
+fn from_array() {
+    let data = [1, 2, 3, 4];
+    let iter = SliceIter::from_array(&data);
+    assert_eq!(iter.start(), SliceIter::from(&data[..]).start());
+    assert_eq!(iter.end(), SliceIter::from(&data[..]).end());
+    assert_eq!(SliceIter::<u8>::from_array(&[]).len(), 0);
+}
+
+#[test]
+fn as_slice_mut() {
+    let mut data = [1, 2, 3, 4];
+    let mut iter = SliceIterMut::from(&mut data[..]);
+    iter.next();
+    iter.as_slice_mut()[0] = 20;
+    iter.as_slice_mut().reverse();
+    assert_eq!(iter.next(), Some(&mut 4));
+    assert_eq!(iter.next(), Some(&mut 3));
+    assert_eq!(iter.next(), Some(&mut 20));
+    assert_eq!(iter.as_slice_mut(), &mut []);
+}
+
+#[test]
+fn len_bytes() {
+    let data = [1u32, 2, 3, 4, 5];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.next();
+    assert_eq!(iter.remaining(), 4);
+    assert_eq!(iter.len_bytes(), std::mem::size_of_val(iter.as_slice()));
+    assert_eq!(iter.len_bytes(), 16);
+    assert_eq!(SliceIter::<u64>::default().len_bytes(), 0);
+}
+
+#[test]
+fn index_of_ptr() {
+    let data = [1u32, 2, 3, 4, 5];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.next();
+    iter.next_back();
+    for i in 0..iter.len() {
+        let p = unsafe { iter.get_unchecked(i) as *const u32 };
+        assert_eq!(iter.index_of_ptr(p), Some(i));
+    }
+    assert_eq!(iter.index_of_ptr(&data[0]), None);
+    assert_eq!(iter.index_of_ptr(&data[4]), None);
+    assert_eq!(iter.index_of_ptr(iter.end()), None);
+    let misaligned = (iter.start() as usize + 1) as *const u32;
+    assert_eq!(iter.index_of_ptr(misaligned), None);
+}
+
+#[test]
+fn default_aligned() {
+    let iter = SliceIter::<u64>::default();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.as_slice(), &[]);
+    assert!(!iter.start().is_null());
+    assert!(iter.start().is_aligned());
+    let mut iter = SliceIterMut::<u64>::default();
+    assert!(iter.next().is_none());
+    assert!(iter.start().is_aligned());
+}
+
+#[test]
+fn into_core_iter() {
+    let data = [1, 2, 3, 4];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.next();
+    let mut core_iter = iter.into_core_iter();
+    assert_eq!(core_iter.as_slice(), &[2, 3, 4]);
+    core_iter.next_back();
+    assert_eq!(SliceIter::from(core_iter).as_slice(), &[2, 3]);
+}
+
+#[test]
+fn collect_array() {
+    let data = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let mut iter = SliceIter::from(&data[..]);
+    let mut records = Vec::new();
+    while let Some(record) = iter.collect_array::<4>() {
+        records.push(record);
+    }
+    assert_eq!(records, [[1, 2, 3, 4], [5, 6, 7, 8]]);
+    assert_eq!(iter.as_slice(), &[9, 10]);
+    assert_eq!(iter.collect_array::<0>(), Some([]));
+}
+
+#[test]
+fn try_join() {
+    let data = [1, 2, 3, 4, 5];
+    let iter = SliceIter::from(&data[..]);
+    for i in 0..data.len() + 1 {
+        let (a, b) = iter.split_at(i);
+        assert_eq!(a.try_join(b).map(|j| j.as_slice()), Some(&data[..]));
+        if i > 0 && i < data.len() {
+            assert!(b.try_join(a).is_none());
+        }
+    }
+    let (a, b) = iter.split_at(2);
+    assert!(a.try_join(b.skip_raw(1)).is_none());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "start pointer is after the end pointer")]
+fn len_unordered() {
+    let data = [1, 2, 3];
+    let mut iter = SliceIter::from(&data[..]);
+    unsafe {
+        iter.set_end(data.as_ptr());
+        iter.set_start(data.as_ptr().add(2));
+    }
+    iter.len();
+}
+
+#[test]
+fn find_ptr_points_into_the_slice() {
+    let data = [1u32, 2, 3, 4, 5];
+    let mut iter = SliceIter::from(&data[..]);
+    let base = iter.as_slice().as_ptr();
+    let p = iter.find_ptr(|&x| x == 3).unwrap();
+    assert_eq!(p, unsafe { base.add(2) });
+    assert_eq!(unsafe { *p.add(2) }, 5);
+
+    let mut iter = SliceIter::from(&data[..]);
+    let prefix = iter.take_while_raw(|&x| x < 3);
+    assert_eq!(prefix.as_slice(), &[1, 2]);
+    assert_eq!(iter.as_slice(), &[3, 4, 5]);
+    assert_eq!(iter.sum::<u32>(), 12);
+}
+
+#[test]
+fn byte_position() {
+    let bytes = b"hello, world";
+    let mut iter = SliceIter::from(&bytes[..]);
+    assert_eq!(iter.byte_position(|&b| b == b','), SliceIter::from(&bytes[..]).position(|&b| b == b','));
+    assert_eq!(iter.as_slice(), b" world");
+
+    let words = [1u32, 2, 3, 4, 5];
+    let mut iter = SliceIter::from(&words[..]);
+    assert_eq!(iter.byte_position(|&x| x == 4), Some(3 * 4));
+    assert_eq!(iter.byte_position(|&x| x == 4), None);
+    assert_eq!(iter.len(), 0);
+}
+
+#[test]
+fn write_all() {
+    let mut buf = [0; 5];
+    let mut iter = SliceIterMut::from(&mut buf[..]);
+    assert_eq!(iter.write_all(vec![1, 2]), 2);
+    assert_eq!(iter.len(), 3);
+    let mut src = 10..20;
+    assert_eq!(iter.write_all(&mut src), 3);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(src.next(), Some(13));
+    assert_eq!(iter.write_all(&mut src), 0);
+    assert_eq!(src.next(), Some(14));
+    assert_eq!(buf, [1, 2, 10, 11, 12]);
+}
+
+#[test]
+#[should_panic]
+fn rchunks_exact_zero() {
+    SliceIter::from(&[1, 2, 3][..]).rchunks_exact(0);
+}
+
+#[test]
+fn raw_parts() {
+    let data = [1, 2, 3, 4];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.next();
+    let (ptr, len) = iter.into_raw_parts();
+    assert_eq!(len, 3);
+    let iter2 = unsafe { SliceIter::from_raw_parts(ptr, len) };
+    assert_eq!(iter2.start(), iter.start());
+    assert_eq!(iter2.end(), iter.end());
+
+    let mut data = [1, 2, 3, 4];
+    let mut iter = SliceIterMut::from(&mut data[..]);
+    iter.next_back();
+    let (ptr, len) = iter.into_raw_parts();
+    let mut iter = unsafe { SliceIterMut::from_raw_parts(ptr, len) };
+    assert_eq!(iter.as_slice_mut(), &mut [1, 2, 3]);
+}
+
+#[test]
+fn shrink() {
+    let data = [1, 2, 3, 4, 5];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.shrink(1, 2);
+    assert_eq!(iter.as_slice(), &[2, 3]);
+    iter.shrink(0, 5);
+    assert_eq!(iter.as_slice(), &[]);
+    assert_eq!(iter.start(), data[1..].as_ptr());
+
+    let mut iter = SliceIter::from(&data[..]);
+    iter.shrink(10, 10);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.start(), iter.end());
+}
+
+#[test]
+fn split_off_nth() {
+    let data = [1, 2, 3, 4, 5];
+    let mut iter = SliceIter::from(&data[..]);
+    assert_eq!(iter.split_off_nth(1), Ok(&2));
+    assert_eq!(iter.split_off_nth(0), Ok(&3));
+    assert_eq!(iter.split_off_nth(5), Err(2));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.split_off_nth(0), Err(0));
+}
+
+#[test]
+fn try_from_slice() {
+    use rawslice::SliceIterError;
+
+    let units = [(), (), ()];
+    assert_eq!(SliceIter::try_from_slice(&units[..]).err(), Some(SliceIterError::ZeroSized));
+    let p = units.as_ptr();
+    assert_eq!(unsafe { SliceIter::new_checked(p, p) }.err(), Some(SliceIterError::ZeroSized));
+
+    let bytes = [1u8, 2, 3];
+    assert_eq!(SliceIter::try_from_slice(&bytes[..]).unwrap().as_slice(), &bytes[..]);
+}
+
+#[test]
+fn try_index() {
+    use rawslice::IndexError;
+
+    let data = [1, 2, 3, 4];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.next();
+    assert_eq!(iter.try_index(0), Ok(&2));
+    assert_eq!(iter.try_index(2), Ok(&4));
+    let err = iter.try_index(3).unwrap_err();
+    assert_eq!(err, IndexError { index: 3, len: 3 });
+    assert_eq!(err.to_string(), "index 3 is out of bounds for length 3");
+}
+
+#[test]
+fn advance_to() {
+    let data = [1, 2, 3, 4, 5];
+    let mut iter = SliceIter::from(&data[..]);
+    unsafe {
+        iter.advance_to(&data[3]);
+    }
+    assert_eq!(iter.as_slice(), &[4, 5]);
+    assert_eq!(iter.next(), Some(&4));
+    unsafe {
+        iter.advance_to(iter.end());
+    }
+    assert_eq!(iter.as_slice(), &[]);
+}
+
+#[test]
+fn as_slice_outlives_iter() {
+    let data = [1, 2, 3, 4];
+    let mut iter = SliceIter::from(&data[..]);
+    iter.next();
+    let tail = iter.as_slice();
+    let rest = iter.remaining_slice();
+    let moved = iter;
+    assert_eq!(moved.count(), 3);
+    assert_eq!(tail, &[2, 3, 4]);
+    assert_eq!(rest, tail);
+}
+
+#[test]
+fn as_bytes() {
+    let data = [1u32, 0x0102_0304, u32::MAX];
+    let iter = SliceIter::from(&data[..]);
+    let bytes = unsafe { iter.as_bytes() };
+    assert_eq!(bytes.len(), 4 * data.len());
+    let back: Vec<u32> = bytes.chunks(4)
+        .map(|b| u32::from_ne_bytes([b[0], b[1], b[2], b[3]]))
+        .collect();
+    assert_eq!(back, data);
+}
+
+#[test]
+fn fold_reporting() {
+    use rawslice::FoldWhile;
+
+    let data = [1, 2, 3, 4, 5];
+    let mut iter = SliceIter::from(&data[..]);
+    let res = iter.fold_reporting(0, |acc, &x| {
+        if acc + x > 5 { FoldWhile::Done(acc) } else { FoldWhile::Continue(acc + x) }
+    });
+    assert_eq!(res, (3, true));
+    assert_eq!(iter.as_slice(), &[4, 5]);
+
+    let res = iter.fold_reporting(0, |acc, &x| FoldWhile::Continue(acc + x));
+    assert_eq!(res, (9, false));
+    assert_eq!(iter.len(), 0);
+}
+
+#[test]
+fn adaptors_copy_clone() {
+    let data = [1, 2, 3, 4, 5];
+    let iter = SliceIter::from(&data[..]);
+
+    let mut chunks = iter.rchunks(2);
+    chunks.next();
+    let mut copy = chunks;
+    assert_eq!(copy.next(), Some(&[2, 3][..]));
+    assert_eq!(chunks.next(), Some(&[2, 3][..]));
+    assert_eq!(chunks.count(), 1);
+
+    let mut windows = iter.windows(3);
+    windows.next();
+    let copy = windows;
+    assert_eq!(windows.next(), Some(&[2, 3, 4][..]));
+    assert!(copy.eq(vec![&[2, 3, 4][..], &[3, 4, 5][..]]));
+
+    let mut split = iter.split(|&x| x == 3);
+    split.next();
+    let cloned = split.clone();
+    assert_eq!(split.next(), Some(&[4, 5][..]));
+    assert!(cloned.eq(vec![&[4, 5][..]]));
+}
+
+#[test]
+fn find_with_state() {
+    // the position where the bracket depth first returns to zero
+    let depth_search = |text: &[u8]| {
+        SliceIter::from(text).find_with_state(0i32, |depth, &c| {
+            match c {
+                b'[' => *depth += 1,
+                b']' => *depth -= 1,
+                _ => {}
+            }
+            *depth == 0
+        })
+    };
+    assert_eq!(depth_search(b"[[x][y]]z"), Some((7, 0)));
+    assert_eq!(depth_search(b"x"), Some((0, 0)));
+    assert_eq!(depth_search(b"[[]"), None);
+    assert_eq!(depth_search(b""), None);
+}
+
+#[test]
+#[should_panic]
+fn rotate_left_too_far() {
+    let mut data = [1, 2, 3];
+    SliceIterMut::from(&mut data[..]).rotate_left(4);
+}
+
+#[test]
+#[should_panic]
+fn swap_out_of_bounds() {
+    let mut data = [1, 2, 3];
+    let mut iter = SliceIterMut::from(&mut data[..]);
+    iter.next();
+    iter.swap(0, 2);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "misaligned")]
+fn new_misaligned() {
+    let data = [0u32; 4];
+    let start = (data.as_ptr() as *const u8).wrapping_add(1) as *const u32;
+    unsafe {
+        SliceIter::new(start, start.wrapping_add(2));
+    }
+}
+
+#[test]
+fn len_checked() {
+    let data = [0u32; 4];
+    let mut iter = SliceIter::from(&data[..]);
+    assert_eq!(iter.len_checked(), Some(4));
+    iter.next();
+    assert_eq!(iter.len_checked(), Some(3));
+    unsafe {
+        let end = (iter.end() as *const u8).wrapping_sub(1) as *const u32;
+        iter.set_end(end);
+        assert_eq!(iter.len_checked(), None);
+        iter.set_end(iter.start().wrapping_sub(1));
+        assert_eq!(iter.len_checked(), None);
+    }
+}
+
+#[test]
+#[should_panic]
+fn for_each_window_zero() {
+    SliceIter::from(&[1, 2, 3][..]).for_each_window(0, |_| {});
+}
+
+#[test]
+fn search_leaves_iter_past_match() {
+    // every match position, in and after the unrolled groups of four
+    let data: Vec<usize> = (0..13).collect();
+    for len in 0..data.len() {
+        let data = &data[..len];
+        for i in 0..len {
+            let rest = &data[i + 1..];
+
+            let mut iter = SliceIter::from(data);
+            assert_eq!(iter.find(|&&x| x == i), Some(&i));
+            assert_eq!(iter.as_slice(), rest);
+
+            let mut iter = SliceIter::from(data);
+            assert_eq!(iter.position(|&x| x == i), Some(i));
+            assert_eq!(iter.as_slice(), rest);
+
+            let mut iter = SliceIter::from(data);
+            assert!(!iter.all(|&x| x != i));
+            assert_eq!(iter.as_slice(), rest);
+
+            let mut iter = SliceIter::from(data);
+            assert!(iter.any(|&x| x == i));
+            assert_eq!(iter.as_slice(), rest);
+
+            let mut iter = SliceIter::from(data);
+            assert_eq!(iter.rposition(|&x| x == i), Some(i));
+            assert_eq!(iter.as_slice(), &data[..i]);
+        }
+        let mut iter = SliceIter::from(data);
+        assert_eq!(iter.find(|&&x| x == len), None);
+        assert_eq!(iter.len(), 0);
+    }
+}
+
+#[test]
+fn narrowing_keeps_iterator_type() {
+    // compile time check that the splitting and narrowing methods return
+    // the same, unrolled, iterator type
+    fn is_slice_iter<'a, T>(iter: SliceIter<'a, T>) -> SliceIter<'a, T> { iter }
+
+    let data = [1, 2, 3, 4, 5, 6];
+    let iter = SliceIter::from(&data[..]);
+    let (a, b) = iter.split_at(2);
+    is_slice_iter(a);
+    is_slice_iter(b);
+    is_slice_iter(iter.subslice_checked(1..3).unwrap());
+    is_slice_iter(iter.get_range(1..).unwrap());
+    is_slice_iter(iter.take_raw(3));
+    is_slice_iter(iter.skip_raw(3));
+    for chunk in iter.chunk_iters(4) {
+        is_slice_iter(chunk);
+    }
+}
+
+#[test]
+fn from_maybe_uninit() {
+    use std::mem::MaybeUninit;
+
+    let mut buf = [MaybeUninit::<u32>::uninit(); 5];
+    for (i, elt) in buf.iter_mut().enumerate() {
+        elt.write(i as u32 * 3);
+    }
+    let iter = unsafe { SliceIter::from_maybe_uninit(&buf[..]) };
+    assert_eq!(iter.as_slice(), &[0, 3, 6, 9, 12]);
+}
+
+#[test]
+fn dedup_by_key_computes_keys_once() {
+    use std::cell::Cell;
+
+    let data = [1, 1, 2, 3, 3, 3, 4, 1];
+    let calls = Cell::new(0);
+    let deduped: Vec<_> = SliceIter::from(&data[..])
+        .dedup_by_key(|&x| { calls.set(calls.get() + 1); x })
+        .collect();
+    assert_eq!(deduped, [&1, &2, &3, &4, &1]);
+    assert_eq!(calls.get(), data.len());
+}